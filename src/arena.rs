@@ -0,0 +1,325 @@
+//! Fixed-capacity generational arena backed by an [`Array`].
+
+use core::{
+    fmt::{self, Debug},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    mem,
+    ops::{Index, IndexMut},
+};
+
+use crate::{Array, CapacityError};
+
+#[derive(Clone)]
+enum Slot<T> {
+    Occupied { generation: u32, value: T },
+    Vacant { generation: u32, next: usize },
+}
+
+impl<T> Slot<T> {
+    fn generation(&self) -> u32 {
+        match *self {
+            Slot::Occupied { generation, .. } | Slot::Vacant { generation, .. } => generation,
+        }
+    }
+}
+
+/// Typed key to an element of an [`ArrayArena`].
+///
+/// A handle remembers the generation of its slot, so once its element is removed the handle
+/// stops resolving, even if the slot is reused.
+pub struct Handle<T> {
+    index: usize,
+    generation: u32,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Handle<T> {
+    /// Returns the slot index of the handle.
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the generation of the slot when the handle was created.
+    pub const fn generation(&self) -> u32 {
+        self.generation
+    }
+}
+
+impl<T> Clone for Handle<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for Handle<T> {}
+
+impl<T> PartialEq for Handle<T> {
+    fn eq(&self, other: &Self) -> bool {
+        (self.index, self.generation) == (other.index, other.generation)
+    }
+}
+
+impl<T> Eq for Handle<T> {}
+
+impl<T> Hash for Handle<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (self.index, self.generation).hash(state);
+    }
+}
+
+impl<T> Debug for Handle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Handle({}v{})", self.index, self.generation)
+    }
+}
+
+/// Fixed-capacity arena storing up to `L` elements inline, addressed by generational
+/// [`Handle`]s.
+///
+/// Like [`ArraySlab`](crate::ArraySlab), inserts and removes are O(1) and reuse freed slots, but
+/// every removal bumps the slot's generation so stale handles return `None` instead of aliasing
+/// the new element.
+///
+/// # Examples
+///
+/// ```
+/// use arrayy::ArrayArena;
+///
+/// let mut arena = ArrayArena::<&str, 4>::new();
+/// let player = arena.insert("player");
+/// assert_eq!(arena.remove(player), Some("player"));
+/// let enemy = arena.insert("enemy");
+/// // same slot, new generation
+/// assert_eq!(enemy.index(), player.index());
+/// assert_eq!(arena.get(player), None);
+/// assert_eq!(arena.get(enemy), Some(&"enemy"));
+/// ```
+pub struct ArrayArena<T, const L: usize> {
+    slots: Array<Slot<T>, L>,
+    /// Head of the free list, or `slots.len()` if every slot is occupied.
+    next: usize,
+    len: usize,
+}
+
+impl<T, const L: usize> Default for ArrayArena<T, L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const L: usize> ArrayArena<T, L> {
+    /// Creates an empty arena.
+    pub fn new() -> Self {
+        Self {
+            slots: Array::default(),
+            next: 0,
+            len: 0,
+        }
+    }
+
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    pub const fn capacity(&self) -> usize {
+        L
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub const fn is_full(&self) -> bool {
+        self.len == L
+    }
+
+    /// Inserts an element and returns its handle.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arena is full.
+    pub fn insert(&mut self, value: T) -> Handle<T> {
+        match self.try_insert(value) {
+            Ok(handle) => handle,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    /// Inserts an element and returns its handle, or returns the element in a [`CapacityError`]
+    /// if the arena is full.
+    pub fn try_insert(&mut self, value: T) -> Result<Handle<T>, CapacityError<T>> {
+        if self.is_full() {
+            return Err(CapacityError::new(value, self.len, L));
+        }
+        let index = self.next;
+        let generation = if index == self.slots.len() {
+            unsafe {
+                self.slots.push_unchecked(Slot::Occupied {
+                    generation: 0,
+                    value,
+                })
+            };
+            self.next += 1;
+            0
+        } else {
+            let slot = &mut self.slots[index];
+            let generation = slot.generation();
+            match mem::replace(slot, Slot::Occupied { generation, value }) {
+                Slot::Vacant { next, .. } => self.next = next,
+                Slot::Occupied { .. } => unreachable!("free list points at an occupied slot"),
+            }
+            generation
+        };
+        self.len += 1;
+        Ok(Handle {
+            index,
+            generation,
+            marker: PhantomData,
+        })
+    }
+
+    pub fn get(&self, handle: Handle<T>) -> Option<&T> {
+        match self.slots.get(handle.index)? {
+            Slot::Occupied { generation, value } if *generation == handle.generation => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, handle: Handle<T>) -> Option<&mut T> {
+        match self.slots.get_mut(handle.index)? {
+            Slot::Occupied { generation, value } if *generation == handle.generation => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if `handle` refers to a live element.
+    pub fn contains(&self, handle: Handle<T>) -> bool {
+        self.get(handle).is_some()
+    }
+
+    /// Removes and returns the element `handle` refers to, or `None` if the handle is stale.
+    pub fn remove(&mut self, handle: Handle<T>) -> Option<T> {
+        self.get(handle)?;
+        let slot = Slot::Vacant {
+            generation: handle.generation.wrapping_add(1),
+            next: self.next,
+        };
+        match mem::replace(&mut self.slots[handle.index], slot) {
+            Slot::Occupied { value, .. } => {
+                self.next = handle.index;
+                self.len -= 1;
+                Some(value)
+            }
+            Slot::Vacant { .. } => unreachable!(),
+        }
+    }
+
+    /// Retains only the elements that match the predicate.
+    pub fn retain(&mut self, mut f: impl FnMut(Handle<T>, &mut T) -> bool) {
+        for index in 0..self.slots.len() {
+            if let Slot::Occupied { generation, value } = &mut self.slots[index] {
+                let handle = Handle {
+                    index,
+                    generation: *generation,
+                    marker: PhantomData,
+                };
+                if !f(handle, value) {
+                    self.remove(handle);
+                }
+            }
+        }
+    }
+
+    /// Removes all elements. Every outstanding handle becomes stale.
+    pub fn clear(&mut self) {
+        self.next = self.slots.len();
+        for index in (0..self.slots.len()).rev() {
+            let slot = &mut self.slots[index];
+            let generation = match slot {
+                Slot::Occupied { generation, .. } => generation.wrapping_add(1),
+                Slot::Vacant { generation, .. } => *generation,
+            };
+            *slot = Slot::Vacant {
+                generation,
+                next: self.next,
+            };
+            self.next = index;
+        }
+        self.len = 0;
+    }
+
+    /// Returns an iterator over the live elements and their handles, in slot order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (Handle<T>, &T)> {
+        self.slots
+            .as_slice()
+            .iter()
+            .enumerate()
+            .filter_map(|(index, slot)| match slot {
+                Slot::Occupied { generation, value } => Some((
+                    Handle {
+                        index,
+                        generation: *generation,
+                        marker: PhantomData,
+                    },
+                    value,
+                )),
+                Slot::Vacant { .. } => None,
+            })
+    }
+
+    /// Returns a mutable iterator over the live elements and their handles, in slot order.
+    pub fn iter_mut(&mut self) -> impl DoubleEndedIterator<Item = (Handle<T>, &mut T)> {
+        self.slots
+            .as_mut_slice()
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(index, slot)| match slot {
+                Slot::Occupied { generation, value } => Some((
+                    Handle {
+                        index,
+                        generation: *generation,
+                        marker: PhantomData,
+                    },
+                    value,
+                )),
+                Slot::Vacant { .. } => None,
+            })
+    }
+}
+
+impl<T, const L: usize> Index<Handle<T>> for ArrayArena<T, L> {
+    type Output = T;
+
+    /// # Panics
+    ///
+    /// Panics if `handle` is stale.
+    fn index(&self, handle: Handle<T>) -> &Self::Output {
+        self.get(handle).expect("stale arena handle")
+    }
+}
+
+impl<T, const L: usize> IndexMut<Handle<T>> for ArrayArena<T, L> {
+    /// # Panics
+    ///
+    /// Panics if `handle` is stale.
+    fn index_mut(&mut self, handle: Handle<T>) -> &mut Self::Output {
+        self.get_mut(handle).expect("stale arena handle")
+    }
+}
+
+impl<T: Clone, const L: usize> Clone for ArrayArena<T, L> {
+    fn clone(&self) -> Self {
+        Self {
+            slots: self.slots.clone(),
+            next: self.next,
+            len: self.len,
+        }
+    }
+}
+
+impl<T: Debug, const L: usize> Debug for ArrayArena<T, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
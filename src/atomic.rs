@@ -0,0 +1,174 @@
+use core::{
+    cell::UnsafeCell,
+    fmt::{self, Debug},
+    mem::{self, MaybeUninit},
+    ptr,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use crate::{uninit_buf, Array, CapacityError};
+
+/// Fixed-capacity array storing up to `L` elements inline that many threads can push into
+/// through a shared reference.
+///
+/// Each push claims the next slot with an atomic counter and then writes into it, so pushes never
+/// block each other. Elements cannot be read through a shared reference; once the writers are
+/// done, the owner takes them out with [`take`](Self::take) or [`into_array`](Self::into_array).
+///
+/// # Examples
+///
+/// ```
+/// use arrayy::AtomicArray;
+/// use std::thread;
+///
+/// let results = AtomicArray::<u32, 8>::new();
+/// thread::scope(|s| {
+///     for worker in 0..4 {
+///         let results = &results;
+///         s.spawn(move || {
+///             results.push(worker * 10);
+///             results.push(worker * 10 + 1);
+///         });
+///     }
+/// });
+/// let mut arr = results.into_array();
+/// arr.as_mut_slice().sort();
+/// assert_eq!(arr, [0, 1, 10, 11, 20, 21, 30, 31]);
+/// ```
+///
+/// The array is only `Sync` when `T: Send`:
+///
+/// ```compile_fail
+/// use arrayy::AtomicArray;
+/// use std::rc::Rc;
+///
+/// fn assert_sync<T: Sync>(_: &T) {}
+/// assert_sync(&AtomicArray::<Rc<u32>, 4>::new());
+/// ```
+pub struct AtomicArray<T, const L: usize> {
+    buf: UnsafeCell<[MaybeUninit<T>; L]>,
+    len: AtomicUsize,
+}
+
+// Every push writes a slot no other push has claimed, and elements are only read through
+// `&mut self`, so sharing the array only requires the elements to be sendable.
+unsafe impl<T: Send, const L: usize> Sync for AtomicArray<T, L> {}
+
+impl<T, const L: usize> Default for AtomicArray<T, L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const L: usize> AtomicArray<T, L> {
+    /// Creates an empty array.
+    pub const fn new() -> Self {
+        Self {
+            buf: UnsafeCell::new(uninit_buf()),
+            len: AtomicUsize::new(0),
+        }
+    }
+
+    /// Returns the number of claimed slots. Only a snapshot while pushes are in progress.
+    pub fn len(&self) -> usize {
+        self.len.load(Ordering::Relaxed)
+    }
+
+    pub const fn capacity(&self) -> usize {
+        L
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len() == L
+    }
+
+    /// Pushes an element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array is full.
+    pub fn push(&self, val: T) {
+        if let Err(err) = self.try_push(val) {
+            panic!("{}", err);
+        }
+    }
+
+    /// Pushes an element, or returns it in a [`CapacityError`] if the array is full.
+    pub fn try_push(&self, val: T) -> Result<(), CapacityError<T>> {
+        // The counter only hands out slots; the writes are published to the owner by whatever
+        // synchronization ends the shared borrow (e.g. joining the writer threads).
+        match self
+            .len
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |len| {
+                (len < L).then_some(len + 1)
+            }) {
+            Ok(index) => {
+                unsafe {
+                    (self.buf.get() as *mut MaybeUninit<T>)
+                        .add(index)
+                        .write(MaybeUninit::new(val))
+                };
+                Ok(())
+            }
+            Err(len) => Err(CapacityError::new(val, len, L)),
+        }
+    }
+
+    /// Returns the pushed elements as a mutable slice, in the order their slots were claimed.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        let len = *self.len.get_mut();
+        unsafe { &mut *ptr::slice_from_raw_parts_mut(self.buf.get_mut().as_mut_ptr().cast(), len) }
+    }
+
+    /// Takes the pushed elements out as an [`Array`], leaving this one empty.
+    pub fn take(&mut self) -> Array<T, L> {
+        let len = mem::take(self.len.get_mut());
+        let mut arr = Array::default();
+        unsafe {
+            ptr::copy_nonoverlapping(self.buf.get_mut().as_ptr(), arr.buf_mut().as_mut_ptr(), len);
+            arr.set_len_unchecked(len);
+        }
+        arr
+    }
+
+    /// Consumes the array, returning the pushed elements as an [`Array`].
+    pub fn into_array(mut self) -> Array<T, L> {
+        self.take()
+    }
+
+    pub fn clear(&mut self) {
+        drop(self.take());
+    }
+}
+
+impl<T, const L: usize> From<Array<T, L>> for AtomicArray<T, L> {
+    fn from(arr: Array<T, L>) -> Self {
+        let mut atomic = Self::new();
+        let len = arr.len();
+        let arr = mem::ManuallyDrop::new(arr);
+        unsafe {
+            ptr::copy_nonoverlapping(arr.as_ptr(), atomic.buf.get_mut().as_mut_ptr().cast(), len)
+        };
+        *atomic.len.get_mut() = len;
+        atomic
+    }
+}
+
+impl<T, const L: usize> Drop for AtomicArray<T, L> {
+    fn drop(&mut self) {
+        unsafe { ptr::drop_in_place(self.as_mut_slice()) };
+    }
+}
+
+impl<T, const L: usize> Debug for AtomicArray<T, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AtomicArray")
+            .field("len", &self.len())
+            .field("capacity", &L)
+            .finish()
+    }
+}
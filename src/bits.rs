@@ -0,0 +1,452 @@
+use core::{
+    fmt::{self, Debug, Display, Write},
+    ops::{
+        BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not, Shl, ShlAssign, Shr,
+        ShrAssign,
+    },
+};
+
+use crate::{check_below, CapacityError};
+
+const BITS: usize = u64::BITS as usize;
+
+/// Returns the number of `u64` words needed to hold `bits` bits, for use as the `W` parameter of
+/// [`BitArray`].
+///
+/// # Examples
+///
+/// ```
+/// use arrayy::{words_for, BitArray};
+/// assert_eq!(words_for(64), 1);
+/// assert_eq!(words_for(100), 2);
+/// let bits = BitArray::<100, { words_for(100) }>::new();
+/// assert_eq!(bits.capacity(), 100);
+/// ```
+pub const fn words_for(bits: usize) -> usize {
+    bits / BITS + (bits % BITS != 0) as usize
+}
+
+/// Fixed-capacity bit vector storing up to `L` bits packed into `W` inline `u64` words.
+///
+/// `W` is the word count and must satisfy `L <= W * 64`, which is checked at compile time. Stable
+/// Rust cannot compute it from `L` inside the type, so it defaults to `1` and `BitArray<L>` covers
+/// up to 64 bits; for more, pass [`words_for(L)`](words_for), e.g.
+/// `BitArray<200, { words_for(200) }>`.
+///
+/// ```compile_fail
+/// use arrayy::BitArray;
+/// let bits = BitArray::<100>::new(); // needs 2 words
+/// ```
+///
+/// Like [`Array`](crate::Array), a `BitArray` has a length that grows with [`push`](Self::push)
+/// up to its capacity `L`. It can also be used as a set of small integers below `L` through
+/// [`insert`](Self::insert), [`remove`](Self::remove) and [`contains`](Self::contains).
+///
+/// # Examples
+///
+/// ```
+/// use arrayy::BitArray;
+///
+/// let mut bits = BitArray::<10>::new();
+/// bits.push(true);
+/// bits.push(false);
+/// bits.push(true);
+/// assert_eq!(bits.len(), 3);
+/// assert_eq!(bits.count_ones(), 2);
+/// bits.toggle(1);
+/// assert_eq!(bits.get(1), Some(true));
+///
+/// let mut set = BitArray::<100, { arrayy::words_for(100) }>::new();
+/// set.insert(3);
+/// set.insert(70);
+/// assert!(set.contains(70));
+/// assert_eq!(set.iter_ones().collect::<Vec<_>>(), vec![3, 70]);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BitArray<const L: usize, const W: usize = 1> {
+    words: [u64; W],
+    len: usize,
+}
+
+impl<const L: usize, const W: usize> Default for BitArray<L, W> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const L: usize, const W: usize> BitArray<L, W> {
+    const WORDS_FIT: () = assert!(L <= W * BITS, "word count too small for bit capacity");
+
+    /// Creates an empty bit array.
+    pub const fn new() -> Self {
+        let () = Self::WORDS_FIT;
+        Self {
+            words: [0; W],
+            len: 0,
+        }
+    }
+
+    /// Creates a bit array of length `L` with every bit cleared.
+    pub const fn zeros() -> Self {
+        let mut bits = Self::new();
+        bits.len = L;
+        bits
+    }
+
+    /// Creates a bit array of length `L` with every bit set.
+    pub fn ones() -> Self {
+        !Self::zeros()
+    }
+
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    pub const fn capacity(&self) -> usize {
+        L
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub const fn is_full(&self) -> bool {
+        self.len == L
+    }
+
+    /// Returns the underlying words. Bit `i` is bit `i % 64` of word `i / 64`, and bits past
+    /// the length are always zero.
+    pub const fn as_words(&self) -> &[u64; W] {
+        &self.words
+    }
+
+    /// Clears the bits past `self.len` to keep the invariant relied upon by equality and counting.
+    fn clear_tail(&mut self) {
+        let (word, bit) = (self.len / BITS, self.len % BITS);
+        let mut rest = word;
+        if bit != 0 {
+            self.words[word] &= (1 << bit) - 1;
+            rest += 1;
+        }
+        for w in &mut self.words[rest..] {
+            *w = 0;
+        }
+    }
+
+    /// Appends a bit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array is full.
+    pub fn push(&mut self, bit: bool) {
+        if let Err(err) = self.try_push(bit) {
+            panic!("{}", err);
+        }
+    }
+
+    /// Appends a bit, or returns it in a [`CapacityError`] if the array is full.
+    pub fn try_push(&mut self, bit: bool) -> Result<(), CapacityError<bool>> {
+        if self.len == L {
+            return Err(CapacityError::new(bit, self.len, L));
+        }
+        self.len += 1;
+        if bit {
+            self.words[(self.len - 1) / BITS] |= 1 << ((self.len - 1) % BITS);
+        }
+        Ok(())
+    }
+
+    /// Removes and returns the last bit, or `None` if the array is empty.
+    pub fn pop(&mut self) -> Option<bool> {
+        let bit = self.get(self.len.checked_sub(1)?)?;
+        self.len -= 1;
+        self.clear_tail();
+        Some(bit)
+    }
+
+    /// Shortens the array to `len` bits. Has no effect if `len >= self.len`.
+    pub fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            self.len = len;
+            self.clear_tail();
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Returns bit `index`, or `None` if it is out of bounds.
+    pub fn get(&self, index: usize) -> Option<bool> {
+        if index < self.len {
+            Some(self.words[index / BITS] & (1 << (index % BITS)) != 0)
+        } else {
+            None
+        }
+    }
+
+    /// Sets bit `index` to `bit`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    pub fn set(&mut self, index: usize, bit: bool) {
+        check_below("index", index, "len", self.len);
+        let mask = 1 << (index % BITS);
+        if bit {
+            self.words[index / BITS] |= mask;
+        } else {
+            self.words[index / BITS] &= !mask;
+        }
+    }
+
+    /// Flips bit `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    pub fn toggle(&mut self, index: usize) {
+        check_below("index", index, "len", self.len);
+        self.words[index / BITS] ^= 1 << (index % BITS);
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn count_zeros(&self) -> usize {
+        self.len - self.count_ones()
+    }
+
+    /// Returns `true` if any bit is set.
+    pub fn any(&self) -> bool {
+        self.words.iter().any(|&w| w != 0)
+    }
+
+    /// Returns `true` if every bit is set. Returns `true` for an empty array.
+    pub fn all(&self) -> bool {
+        self.count_ones() == self.len
+    }
+
+    /// Returns the index of the first set bit.
+    pub fn first_set(&self) -> Option<usize> {
+        self.next_set(0)
+    }
+
+    /// Returns the index of the first set bit at or after `from`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::BitArray;
+    /// let bits: BitArray<8> = [false, true, false, true].into_iter().collect();
+    /// assert_eq!(bits.next_set(0), Some(1));
+    /// assert_eq!(bits.next_set(2), Some(3));
+    /// assert_eq!(bits.next_set(4), None);
+    /// ```
+    pub fn next_set(&self, from: usize) -> Option<usize> {
+        if from >= self.len {
+            return None;
+        }
+        let mut word = from / BITS;
+        let mut bits = self.words[word] & (!0 << (from % BITS));
+        loop {
+            if bits != 0 {
+                return Some(word * BITS + bits.trailing_zeros() as usize);
+            }
+            word += 1;
+            bits = *self.words.get(word)?;
+        }
+    }
+
+    /// Returns an iterator over the bits.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = bool> + ExactSizeIterator + '_ {
+        (0..self.len).map(|i| self.words[i / BITS] & (1 << (i % BITS)) != 0)
+    }
+
+    /// Returns an iterator over the indices of the set bits in ascending order.
+    pub fn iter_ones(&self) -> impl Iterator<Item = usize> + '_ {
+        core::iter::successors(self.first_set(), |&i| self.next_set(i + 1))
+    }
+
+    /// Sets bit `value`, extending the length with cleared bits if needed. Returns `true` if the
+    /// bit was not already set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `value >= L`.
+    pub fn insert(&mut self, value: usize) -> bool {
+        check_below("value", value, "capacity", L);
+        self.len = self.len.max(value + 1);
+        let was_set = self.contains(value);
+        self.set(value, true);
+        !was_set
+    }
+
+    /// Clears bit `value`. Returns `true` if it was set.
+    pub fn remove(&mut self, value: usize) -> bool {
+        let was_set = self.contains(value);
+        if was_set {
+            self.set(value, false);
+        }
+        was_set
+    }
+
+    /// Returns `true` if bit `value` is set. Out-of-bounds values are never contained.
+    pub fn contains(&self, value: usize) -> bool {
+        self.get(value).unwrap_or(false)
+    }
+
+    /// Returns `true` if every bit set in `self` is also set in `other`.
+    pub fn is_subset(&self, other: &Self) -> bool {
+        self.words
+            .iter()
+            .zip(&other.words)
+            .all(|(a, b)| a & !b == 0)
+    }
+
+    /// Returns `true` if `self` and `other` have no set bits in common.
+    pub fn is_disjoint(&self, other: &Self) -> bool {
+        self.words.iter().zip(&other.words).all(|(a, b)| a & b == 0)
+    }
+}
+
+impl<const L: usize, const W: usize> FromIterator<bool> for BitArray<L, W> {
+    /// Creates a bit array from an iterator of bits.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator yields more than `L` bits.
+    fn from_iter<I: IntoIterator<Item = bool>>(iter: I) -> Self {
+        let mut bits = Self::new();
+        bits.extend(iter);
+        bits
+    }
+}
+
+impl<const L: usize, const W: usize> Extend<bool> for BitArray<L, W> {
+    /// Appends the bits of an iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array overflows.
+    fn extend<I: IntoIterator<Item = bool>>(&mut self, iter: I) {
+        for bit in iter {
+            self.push(bit);
+        }
+    }
+}
+
+macro_rules! impl_bit_op {
+    ($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident, $sym:tt) => {
+        impl<const L: usize, const W: usize> $OpAssign for BitArray<L, W> {
+            /// Combines the bits word by word. The length becomes the longer of the two, with
+            /// missing bits treated as cleared.
+            fn $op_assign(&mut self, rhs: Self) {
+                for (a, b) in self.words.iter_mut().zip(rhs.words) {
+                    *a = *a $sym b;
+                }
+                self.len = self.len.max(rhs.len);
+            }
+        }
+
+        impl<const L: usize, const W: usize> $Op for BitArray<L, W> {
+            type Output = Self;
+
+            fn $op(mut self, rhs: Self) -> Self {
+                self.$op_assign(rhs);
+                self
+            }
+        }
+    };
+}
+
+impl_bit_op!(BitAnd, bitand, BitAndAssign, bitand_assign, &);
+impl_bit_op!(BitOr, bitor, BitOrAssign, bitor_assign, |);
+impl_bit_op!(BitXor, bitxor, BitXorAssign, bitxor_assign, ^);
+
+impl<const L: usize, const W: usize> Not for BitArray<L, W> {
+    type Output = Self;
+
+    /// Flips every bit within the length.
+    fn not(mut self) -> Self {
+        for w in &mut self.words {
+            *w = !*w;
+        }
+        self.clear_tail();
+        self
+    }
+}
+
+impl<const L: usize, const W: usize> ShlAssign<usize> for BitArray<L, W> {
+    /// Moves bit `i` to index `i + n`, keeping the length. Bits moved past the end are dropped
+    /// and the first `n` bits are cleared.
+    fn shl_assign(&mut self, n: usize) {
+        let (skip, shift) = (n / BITS, n % BITS);
+        for k in (0..W).rev() {
+            let hi = k.checked_sub(skip).map_or(0, |j| self.words[j]);
+            let lo = k.checked_sub(skip + 1).map_or(0, |j| self.words[j]);
+            self.words[k] = if shift == 0 {
+                hi
+            } else {
+                (hi << shift) | (lo >> (BITS - shift))
+            };
+        }
+        self.clear_tail();
+    }
+}
+
+impl<const L: usize, const W: usize> ShrAssign<usize> for BitArray<L, W> {
+    /// Moves bit `i` to index `i - n`, keeping the length. The first `n` bits are dropped and
+    /// the last `n` bits are cleared.
+    fn shr_assign(&mut self, n: usize) {
+        let (skip, shift) = (n / BITS, n % BITS);
+        for k in 0..W {
+            let lo = k.checked_add(skip).and_then(|j| self.words.get(j)).copied();
+            let hi = k
+                .checked_add(skip + 1)
+                .and_then(|j| self.words.get(j))
+                .copied();
+            let (lo, hi) = (lo.unwrap_or(0), hi.unwrap_or(0));
+            self.words[k] = if shift == 0 {
+                lo
+            } else {
+                (lo >> shift) | (hi << (BITS - shift))
+            };
+        }
+    }
+}
+
+impl<const L: usize, const W: usize> Shl<usize> for BitArray<L, W> {
+    type Output = Self;
+
+    fn shl(mut self, n: usize) -> Self {
+        self <<= n;
+        self
+    }
+}
+
+impl<const L: usize, const W: usize> Shr<usize> for BitArray<L, W> {
+    type Output = Self;
+
+    fn shr(mut self, n: usize) -> Self {
+        self >>= n;
+        self
+    }
+}
+
+impl<const L: usize, const W: usize> Display for BitArray<L, W> {
+    /// Formats the bits as `0`s and `1`s, lowest index first.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for bit in self.iter() {
+            f.write_char(if bit { '1' } else { '0' })?;
+        }
+        Ok(())
+    }
+}
+
+impl<const L: usize, const W: usize> Debug for BitArray<L, W> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "BitArray({})", self)
+    }
+}
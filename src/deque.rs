@@ -0,0 +1,519 @@
+//! Fixed-capacity double-ended queue backed by a ring buffer.
+
+use core::{
+    fmt::{self, Debug},
+    iter::FusedIterator,
+    mem::MaybeUninit,
+    ops::{Index, IndexMut},
+    ptr, slice,
+};
+
+use crate::{uninit_buf, CapacityError};
+
+/// Fixed-capacity double-ended queue storing up to `L` elements inline in a ring buffer.
+///
+/// Pushing and popping at either end is O(1). When full, pushes panic (or fail with a
+/// [`CapacityError`] for the `try_*` variants) unless overwrite mode is enabled, in which case the
+/// element at the opposite end is evicted.
+///
+/// # Examples
+///
+/// ```
+/// use arrayy::ArrayDeque;
+/// let mut dq = ArrayDeque::<i32, 4>::new();
+/// dq.push_back(1);
+/// dq.push_back(2);
+/// dq.push_front(0);
+/// assert_eq!(dq.pop_front(), Some(0));
+/// assert_eq!(dq.pop_back(), Some(2));
+/// assert_eq!(dq[0], 1);
+/// ```
+pub struct ArrayDeque<T, const L: usize> {
+    data: [MaybeUninit<T>; L],
+    head: usize,
+    len: usize,
+    overwrite: bool,
+}
+
+impl<T, const L: usize> Default for ArrayDeque<T, L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const L: usize> ArrayDeque<T, L> {
+    /// Creates an empty deque.
+    pub const fn new() -> Self {
+        Self {
+            data: uninit_buf(),
+            head: 0,
+            len: 0,
+            overwrite: false,
+        }
+    }
+
+    /// Creates an empty deque in overwrite mode. See [`ArrayDeque::set_overwrite`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::ArrayDeque;
+    /// let mut log = ArrayDeque::<i32, 3>::new_overwrite();
+    /// for i in 0..5 {
+    ///     log.push_back(i);
+    /// }
+    /// assert_eq!(log.iter().copied().collect::<Vec<_>>(), vec![2, 3, 4]);
+    /// ```
+    pub const fn new_overwrite() -> Self {
+        Self {
+            data: uninit_buf(),
+            head: 0,
+            len: 0,
+            overwrite: true,
+        }
+    }
+
+    /// Enables or disables overwrite mode. In overwrite mode, `push_back` on a full deque evicts
+    /// the front element and `push_front` evicts the back element instead of panicking.
+    pub fn set_overwrite(&mut self, overwrite: bool) {
+        self.overwrite = overwrite;
+    }
+
+    /// Returns `true` if the deque is in overwrite mode.
+    pub const fn is_overwrite(&self) -> bool {
+        self.overwrite
+    }
+
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    pub const fn capacity(&self) -> usize {
+        L
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub const fn is_full(&self) -> bool {
+        self.len == L
+    }
+
+    fn wrap(&self, index: usize) -> usize {
+        let i = self.head + index;
+        if i >= L {
+            i - L
+        } else {
+            i
+        }
+    }
+
+    fn ptr(&self) -> *const T {
+        self.data.as_ptr() as *const T
+    }
+
+    fn mut_ptr(&mut self) -> *mut T {
+        self.data.as_mut_ptr() as *mut T
+    }
+
+    /// Returns a reference to the element at the specified index from the front, or `None` if out
+    /// of bounds.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            None
+        } else {
+            Some(unsafe { &*self.ptr().add(self.wrap(index)) })
+        }
+    }
+
+    /// Returns a mutable reference to the element at the specified index from the front, or
+    /// `None` if out of bounds.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            None
+        } else {
+            let i = self.wrap(index);
+            Some(unsafe { &mut *self.mut_ptr().add(i) })
+        }
+    }
+
+    pub fn front(&self) -> Option<&T> {
+        self.get(0)
+    }
+
+    pub fn front_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(0)
+    }
+
+    pub fn back(&self) -> Option<&T> {
+        self.get(self.len.wrapping_sub(1))
+    }
+
+    pub fn back_mut(&mut self) -> Option<&mut T> {
+        self.get_mut(self.len.wrapping_sub(1))
+    }
+
+    /// Appends an element to the back of the deque.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the deque is full and not in overwrite mode.
+    pub fn push_back(&mut self, val: T) {
+        if let Err(err) = self.try_push_back(val) {
+            panic!("{}", err);
+        }
+    }
+
+    /// Appends an element to the back of the deque, or returns it in a [`CapacityError`] if the
+    /// deque is full. Never fails in overwrite mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::ArrayDeque;
+    /// let mut dq = ArrayDeque::<i32, 1>::new();
+    /// assert!(dq.try_push_back(1).is_ok());
+    /// assert_eq!(dq.try_push_back(2).unwrap_err().element(), 2);
+    /// ```
+    pub fn try_push_back(&mut self, val: T) -> Result<(), CapacityError<T>> {
+        if self.len == L {
+            if !self.overwrite {
+                return Err(CapacityError::new(val, self.len, L));
+            }
+            self.push_back_overwrite(val);
+            return Ok(());
+        }
+        let i = self.wrap(self.len);
+        unsafe { ptr::write(self.mut_ptr().add(i), val) };
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Appends an element to the back of the deque, evicting and returning the front element if
+    /// the deque is full, regardless of the overwrite mode.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::ArrayDeque;
+    /// let mut dq = ArrayDeque::<i32, 2>::new();
+    /// assert_eq!(dq.push_back_overwrite(1), None);
+    /// assert_eq!(dq.push_back_overwrite(2), None);
+    /// assert_eq!(dq.push_back_overwrite(3), Some(1));
+    /// ```
+    pub fn push_back_overwrite(&mut self, val: T) -> Option<T> {
+        if L == 0 {
+            return Some(val);
+        }
+        let evicted = if self.len == L {
+            self.pop_front()
+        } else {
+            None
+        };
+        let i = self.wrap(self.len);
+        unsafe { ptr::write(self.mut_ptr().add(i), val) };
+        self.len += 1;
+        evicted
+    }
+
+    /// Prepends an element to the front of the deque.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the deque is full and not in overwrite mode.
+    pub fn push_front(&mut self, val: T) {
+        if let Err(err) = self.try_push_front(val) {
+            panic!("{}", err);
+        }
+    }
+
+    /// Prepends an element to the front of the deque, or returns it in a [`CapacityError`] if the
+    /// deque is full. Never fails in overwrite mode.
+    pub fn try_push_front(&mut self, val: T) -> Result<(), CapacityError<T>> {
+        if self.len == L {
+            if !self.overwrite {
+                return Err(CapacityError::new(val, self.len, L));
+            }
+            self.push_front_overwrite(val);
+            return Ok(());
+        }
+        self.head = self.wrap(L - 1);
+        let i = self.head;
+        unsafe { ptr::write(self.mut_ptr().add(i), val) };
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Prepends an element to the front of the deque, evicting and returning the back element if
+    /// the deque is full, regardless of the overwrite mode.
+    pub fn push_front_overwrite(&mut self, val: T) -> Option<T> {
+        if L == 0 {
+            return Some(val);
+        }
+        let evicted = if self.len == L { self.pop_back() } else { None };
+        self.head = self.wrap(L - 1);
+        let i = self.head;
+        unsafe { ptr::write(self.mut_ptr().add(i), val) };
+        self.len += 1;
+        evicted
+    }
+
+    /// Removes the first element and returns it, or `None` if the deque is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        let i = self.head;
+        self.head = self.wrap(1);
+        self.len -= 1;
+        Some(unsafe { ptr::read(self.ptr().add(i)) })
+    }
+
+    /// Removes the last element and returns it, or `None` if the deque is empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let i = self.wrap(self.len);
+        Some(unsafe { ptr::read(self.ptr().add(i)) })
+    }
+
+    /// Returns the contents of the deque as a pair of slices, front to back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::ArrayDeque;
+    /// let mut dq = ArrayDeque::<i32, 4>::new();
+    /// dq.push_back(1);
+    /// dq.push_back(2);
+    /// dq.push_front(0);
+    /// assert_eq!(dq.as_slices(), (&[0][..], &[1, 2][..]));
+    /// ```
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        let p = self.ptr();
+        unsafe {
+            if self.head + self.len <= L {
+                (slice::from_raw_parts(p.add(self.head), self.len), &[])
+            } else {
+                let first = L - self.head;
+                (
+                    slice::from_raw_parts(p.add(self.head), first),
+                    slice::from_raw_parts(p, self.len - first),
+                )
+            }
+        }
+    }
+
+    /// Returns the contents of the deque as a pair of mutable slices, front to back.
+    pub fn as_mut_slices(&mut self) -> (&mut [T], &mut [T]) {
+        let (head, len) = (self.head, self.len);
+        let p = self.mut_ptr();
+        unsafe {
+            if head + len <= L {
+                (slice::from_raw_parts_mut(p.add(head), len), &mut [])
+            } else {
+                let first = L - head;
+                (
+                    slice::from_raw_parts_mut(p.add(head), first),
+                    slice::from_raw_parts_mut(p, len - first),
+                )
+            }
+        }
+    }
+
+    /// Rearranges the internal storage so that the elements are contiguous, and returns them as a
+    /// mutable slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::ArrayDeque;
+    /// let mut dq = ArrayDeque::<i32, 3>::new();
+    /// dq.push_back(1);
+    /// dq.push_back(2);
+    /// dq.push_front(0);
+    /// assert_eq!(dq.make_contiguous(), &[0, 1, 2]);
+    /// assert_eq!(dq.as_slices().1, &[]);
+    /// ```
+    pub fn make_contiguous(&mut self) -> &mut [T] {
+        if self.head + self.len > L {
+            self.data.rotate_left(self.head);
+            self.head = 0;
+        }
+        self.as_mut_slices().0
+    }
+
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> {
+        let (a, b) = self.as_slices();
+        a.iter().chain(b.iter())
+    }
+
+    pub fn iter_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut T> {
+        let (a, b) = self.as_mut_slices();
+        a.iter_mut().chain(b.iter_mut())
+    }
+
+    /// Clears the deque, removing all elements.
+    pub fn clear(&mut self) {
+        let (a, b) = self.as_mut_slices();
+        let (a, b) = (a as *mut [T], b as *mut [T]);
+        self.head = 0;
+        self.len = 0;
+        unsafe {
+            ptr::drop_in_place(a);
+            ptr::drop_in_place(b);
+        }
+    }
+}
+
+impl<T, const L: usize> Drop for ArrayDeque<T, L> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: Clone, const L: usize> Clone for ArrayDeque<T, L> {
+    fn clone(&self) -> Self {
+        let mut dq = Self::new();
+        dq.overwrite = self.overwrite;
+        dq.extend(self.iter().cloned());
+        dq
+    }
+}
+
+impl<T: Debug, const L: usize> Debug for ArrayDeque<T, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, const L: usize, const M: usize> PartialEq<ArrayDeque<T, M>>
+    for ArrayDeque<T, L>
+{
+    fn eq(&self, other: &ArrayDeque<T, M>) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, const L: usize> Eq for ArrayDeque<T, L> {}
+
+impl<T, const L: usize> Index<usize> for ArrayDeque<T, L> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        let len = self.len;
+        match self.get(index) {
+            Some(elt) => elt,
+            None => panic!("index ({}) >= len ({})", index, len),
+        }
+    }
+}
+
+impl<T, const L: usize> IndexMut<usize> for ArrayDeque<T, L> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.len;
+        match self.get_mut(index) {
+            Some(elt) => elt,
+            None => panic!("index ({}) >= len ({})", index, len),
+        }
+    }
+}
+
+impl<T, const L: usize> FromIterator<T> for ArrayDeque<T, L> {
+    /// Creates a deque from an iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator yields more than `L` elements.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut dq = Self::new();
+        dq.extend(iter);
+        dq
+    }
+}
+
+impl<T, const L: usize> Extend<T> for ArrayDeque<T, L> {
+    /// Appends the elements of an iterator to the back of the deque.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the deque overflows and is not in overwrite mode.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for elt in iter {
+            self.push_back(elt);
+        }
+    }
+}
+
+/// An iterator that moves out of an [`ArrayDeque`], front to back.
+pub struct IntoIter<T, const L: usize> {
+    dq: ArrayDeque<T, L>,
+}
+
+impl<T, const L: usize> IntoIterator for ArrayDeque<T, L> {
+    type Item = T;
+    type IntoIter = IntoIter<T, L>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        IntoIter { dq: self }
+    }
+}
+
+impl<'a, T, const L: usize> IntoIterator for &'a ArrayDeque<T, L> {
+    type Item = &'a T;
+    type IntoIter = core::iter::Chain<slice::Iter<'a, T>, slice::Iter<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let (a, b) = self.as_slices();
+        a.iter().chain(b.iter())
+    }
+}
+
+impl<'a, T, const L: usize> IntoIterator for &'a mut ArrayDeque<T, L> {
+    type Item = &'a mut T;
+    type IntoIter = core::iter::Chain<slice::IterMut<'a, T>, slice::IterMut<'a, T>>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let (a, b) = self.as_mut_slices();
+        a.iter_mut().chain(b.iter_mut())
+    }
+}
+
+impl<T, const L: usize> Iterator for IntoIter<T, L> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.dq.pop_front()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.dq.len, Some(self.dq.len))
+    }
+}
+
+impl<T, const L: usize> DoubleEndedIterator for IntoIter<T, L> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.dq.pop_back()
+    }
+}
+
+impl<T, const L: usize> ExactSizeIterator for IntoIter<T, L> {}
+
+impl<T, const L: usize> FusedIterator for IntoIter<T, L> {}
+
+impl<T: Clone, const L: usize> Clone for IntoIter<T, L> {
+    fn clone(&self) -> Self {
+        Self {
+            dq: self.dq.clone(),
+        }
+    }
+}
+
+impl<T: Debug, const L: usize> Debug for IntoIter<T, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IntoIter").field(&self.dq).finish()
+    }
+}
@@ -0,0 +1,72 @@
+use core::fmt::{self, Debug, Display};
+
+/// Error returned when an operation would exceed the capacity of a fixed-size container.
+///
+/// The rejected element(s) are handed back so the caller can decide what to do with them.
+///
+/// # Examples
+///
+/// ```
+/// use arrayy::array;
+/// let mut arr = array!(1, 2, 3);
+/// let err = arr.try_push(4).unwrap_err();
+/// assert_eq!(err.len(), 3);
+/// assert_eq!(err.capacity(), 3);
+/// assert_eq!(err.element(), 4);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct CapacityError<T = ()> {
+    element: T,
+    len: usize,
+    capacity: usize,
+}
+
+#[allow(clippy::len_without_is_empty)]
+impl<T> CapacityError<T> {
+    pub const fn new(element: T, len: usize, capacity: usize) -> Self {
+        Self {
+            element,
+            len,
+            capacity,
+        }
+    }
+
+    /// Returns the rejected element(s).
+    pub fn element(self) -> T {
+        self.element
+    }
+
+    /// Returns the length of the container at the time of the failed operation.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns the capacity of the container.
+    pub const fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Discards the rejected element(s), keeping only the length and capacity.
+    pub fn simplify(self) -> CapacityError {
+        CapacityError::new((), self.len, self.capacity)
+    }
+}
+
+impl<T> Display for CapacityError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "insufficient capacity: len ({}), capacity ({})",
+            self.len, self.capacity
+        )
+    }
+}
+
+impl<T> Debug for CapacityError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "CapacityError: {}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T> std::error::Error for CapacityError<T> {}
@@ -0,0 +1,514 @@
+//! Fixed-size two-dimensional grid stored inline.
+
+use core::{
+    ops::{Index, IndexMut},
+    slice,
+};
+
+use crate::check_below;
+
+const NEIGHBORS4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+const NEIGHBORS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+/// Fixed-size `W` x `H` grid stored inline in row-major order and indexed by `(x, y)`.
+///
+/// Unlike [`Array`](crate::Array), every cell of a grid is always initialized.
+///
+/// # Examples
+///
+/// ```
+/// use arrayy::Grid;
+///
+/// let mut board = Grid::<u8, 3, 2>::default();
+/// board[(2, 1)] = 7;
+/// assert_eq!(board.row(1), &[0, 0, 7]);
+/// assert_eq!(board.column(2).copied().collect::<Vec<_>>(), vec![0, 7]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Grid<T, const W: usize, const H: usize> {
+    rows: [[T; W]; H],
+}
+
+impl<T: Default, const W: usize, const H: usize> Default for Grid<T, W, H> {
+    fn default() -> Self {
+        Self::from_fn(|_, _| T::default())
+    }
+}
+
+impl<T, const W: usize, const H: usize> Grid<T, W, H> {
+    /// Creates a grid from its rows.
+    pub const fn new(rows: [[T; W]; H]) -> Self {
+        Self { rows }
+    }
+
+    /// Creates a grid whose cell at `(x, y)` is `f(x, y)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::Grid;
+    /// let grid = Grid::<usize, 2, 2>::from_fn(|x, y| y * 2 + x);
+    /// assert_eq!(grid.as_slice(), &[0, 1, 2, 3]);
+    /// ```
+    pub fn from_fn(mut f: impl FnMut(usize, usize) -> T) -> Self {
+        Self {
+            rows: core::array::from_fn(|y| core::array::from_fn(|x| f(x, y))),
+        }
+    }
+
+    pub const fn width(&self) -> usize {
+        W
+    }
+
+    pub const fn height(&self) -> usize {
+        H
+    }
+
+    /// Returns the number of cells, `W * H`.
+    pub const fn len(&self) -> usize {
+        W * H
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        W * H == 0
+    }
+
+    /// Returns `true` if `(x, y)` lies inside the grid.
+    pub const fn contains(&self, x: usize, y: usize) -> bool {
+        x < W && y < H
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        self.rows.get(y)?.get(x)
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        self.rows.get_mut(y)?.get_mut(x)
+    }
+
+    /// Returns the cells in row-major order.
+    pub fn as_slice(&self) -> &[T] {
+        // `[[T; W]; H]` is laid out as `W * H` contiguous `T`s.
+        unsafe { slice::from_raw_parts(self.rows.as_ptr().cast(), W * H) }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        unsafe { slice::from_raw_parts_mut(self.rows.as_mut_ptr().cast(), W * H) }
+    }
+
+    pub fn as_rows(&self) -> &[[T; W]; H] {
+        &self.rows
+    }
+
+    pub fn into_rows(self) -> [[T; W]; H] {
+        self.rows
+    }
+
+    /// Returns row `y`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `y >= H`.
+    pub fn row(&self, y: usize) -> &[T; W] {
+        &self.rows[y]
+    }
+
+    /// Returns row `y` mutably.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `y >= H`.
+    pub fn row_mut(&mut self, y: usize) -> &mut [T; W] {
+        &mut self.rows[y]
+    }
+
+    /// Returns an iterator over the rows, top to bottom.
+    pub fn rows(&self) -> slice::Iter<'_, [T; W]> {
+        self.rows.iter()
+    }
+
+    pub fn rows_mut(&mut self) -> slice::IterMut<'_, [T; W]> {
+        self.rows.iter_mut()
+    }
+
+    /// Returns an iterator over column `x`, top to bottom.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x >= W`.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        check_below("x", x, "width", W);
+        self.rows.iter().map(move |row| &row[x])
+    }
+
+    /// Returns a mutable iterator over column `x`, top to bottom.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `x >= W`.
+    pub fn column_mut(
+        &mut self,
+        x: usize,
+    ) -> impl DoubleEndedIterator<Item = &mut T> + ExactSizeIterator {
+        check_below("x", x, "width", W);
+        self.rows.iter_mut().map(move |row| &mut row[x])
+    }
+
+    /// Returns an iterator over the columns, left to right.
+    pub fn columns(&self) -> impl DoubleEndedIterator<Item = impl Iterator<Item = &T>> {
+        (0..W).map(move |x| self.column(x))
+    }
+
+    /// Returns an iterator over the cells in row-major order.
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    pub fn iter_mut(&mut self) -> slice::IterMut<'_, T> {
+        self.as_mut_slice().iter_mut()
+    }
+
+    /// Returns an iterator over every `(x, y)` position in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        (0..H).flat_map(|y| (0..W).map(move |x| (x, y)))
+    }
+
+    /// Returns an iterator over `((x, y), &cell)` pairs in row-major order.
+    pub fn enumerate(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(self.iter())
+    }
+
+    /// Returns the in-bounds positions above, left of, right of and below `(x, y)`.
+    ///
+    /// # Examples
+    ///
+    /// Flood fill with an [`Array`](crate::Array) as the work stack:
+    ///
+    /// ```
+    /// use arrayy::{Array, Grid};
+    ///
+    /// let mut grid = Grid::new([
+    ///     [0, 0, 1],
+    ///     [1, 0, 1],
+    ///     [0, 1, 0],
+    /// ]);
+    /// let mut stack = Array::<(usize, usize), 9>::default();
+    /// stack.push((0, 0));
+    /// while let Some((x, y)) = stack.pop() {
+    ///     if grid[(x, y)] == 0 {
+    ///         grid[(x, y)] = 2;
+    ///         stack.extend(grid.neighbors4(x, y).filter(|&p| grid[p] == 0));
+    ///     }
+    /// }
+    /// assert_eq!(grid.row(0), &[2, 2, 1]);
+    /// assert_eq!(grid.row(1), &[1, 2, 1]);
+    /// assert_eq!(grid.row(2), &[0, 1, 0]);
+    /// ```
+    pub fn neighbors4(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        neighbors::<W, H>(x, y, &NEIGHBORS4)
+    }
+
+    /// Returns the in-bounds positions of the eight cells surrounding `(x, y)`, in row-major
+    /// order.
+    pub fn neighbors8(&self, x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
+        neighbors::<W, H>(x, y, &NEIGHBORS8)
+    }
+
+    /// Returns a view of the `width` x `height` sub-grid whose top-left corner is `(x, y)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sub-grid does not fit inside the grid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::Grid;
+    /// let grid = Grid::<usize, 4, 4>::from_fn(|x, y| y * 4 + x);
+    /// let view = grid.view(1, 2, 2, 2);
+    /// assert_eq!(view.row(0), &[9, 10]);
+    /// assert_eq!(view[(1, 1)], 14);
+    /// ```
+    pub fn view(&self, x: usize, y: usize, width: usize, height: usize) -> GridView<'_, T, W> {
+        check_view::<W, H>(x, y, width, height);
+        GridView {
+            rows: &self.rows[y..y + height],
+            x,
+            width,
+        }
+    }
+
+    /// Returns a mutable view of the `width` x `height` sub-grid whose top-left corner is
+    /// `(x, y)`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sub-grid does not fit inside the grid.
+    pub fn view_mut(
+        &mut self,
+        x: usize,
+        y: usize,
+        width: usize,
+        height: usize,
+    ) -> GridViewMut<'_, T, W> {
+        check_view::<W, H>(x, y, width, height);
+        GridViewMut {
+            rows: &mut self.rows[y..y + height],
+            x,
+            width,
+        }
+    }
+
+    /// Maps each cell to a new grid of the same size.
+    pub fn map<X>(self, mut f: impl FnMut(T) -> X) -> Grid<X, W, H> {
+        Grid {
+            rows: self.rows.map(|row| row.map(&mut f)),
+        }
+    }
+}
+
+impl<T, const N: usize> Grid<T, N, N> {
+    /// Transposes a square grid in place, swapping `(x, y)` with `(y, x)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::Grid;
+    /// let mut grid = Grid::new([[1, 2], [3, 4]]);
+    /// grid.transpose();
+    /// assert_eq!(grid.into_rows(), [[1, 3], [2, 4]]);
+    /// ```
+    pub fn transpose(&mut self) {
+        for y in 0..N {
+            for x in y + 1..N {
+                let (upper, lower) = self.rows.split_at_mut(x);
+                core::mem::swap(&mut upper[y][x], &mut lower[0][y]);
+            }
+        }
+    }
+}
+
+fn neighbors<const W: usize, const H: usize>(
+    x: usize,
+    y: usize,
+    offsets: &'static [(isize, isize)],
+) -> impl Iterator<Item = (usize, usize)> {
+    offsets.iter().filter_map(move |&(dx, dy)| {
+        let nx = offset(x, dx)?;
+        let ny = offset(y, dy)?;
+        (nx < W && ny < H).then_some((nx, ny))
+    })
+}
+
+fn offset(pos: usize, delta: isize) -> Option<usize> {
+    if delta < 0 {
+        pos.checked_sub(delta.unsigned_abs())
+    } else {
+        pos.checked_add(delta as usize)
+    }
+}
+
+fn check_view<const W: usize, const H: usize>(x: usize, y: usize, width: usize, height: usize) {
+    if x > W || width > W - x || y > H || height > H - y {
+        panic!(
+            "sub-grid ({}, {}) + ({}, {}) exceeds grid size ({}, {})",
+            x, y, width, height, W, H
+        );
+    }
+}
+
+impl<T, const W: usize, const H: usize> From<[[T; W]; H]> for Grid<T, W, H> {
+    fn from(rows: [[T; W]; H]) -> Self {
+        Self { rows }
+    }
+}
+
+impl<T, const W: usize, const H: usize> Index<(usize, usize)> for Grid<T, W, H> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        &self.rows[y][x]
+    }
+}
+
+impl<T, const W: usize, const H: usize> IndexMut<(usize, usize)> for Grid<T, W, H> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        &mut self.rows[y][x]
+    }
+}
+
+impl<'a, T, const W: usize, const H: usize> IntoIterator for &'a Grid<T, W, H> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, const W: usize, const H: usize> IntoIterator for &'a mut Grid<T, W, H> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+/// A borrowed rectangular region of a [`Grid`], indexed relative to its top-left corner.
+///
+/// Created by [`Grid::view`].
+#[derive(Clone, Copy, Debug)]
+pub struct GridView<'a, T, const W: usize> {
+    rows: &'a [[T; W]],
+    x: usize,
+    width: usize,
+}
+
+impl<'a, T, const W: usize> GridView<'a, T, W> {
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    pub const fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&'a T> {
+        if x < self.width {
+            self.rows.get(y).map(|row| &row[self.x + x])
+        } else {
+            None
+        }
+    }
+
+    /// Returns row `y` of the view.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `y >= height`.
+    pub fn row(&self, y: usize) -> &'a [T] {
+        &self.rows[y][self.x..self.x + self.width]
+    }
+
+    /// Returns an iterator over the rows of the view, top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> {
+        let (x, width) = (self.x, self.width);
+        self.rows.iter().map(move |row| &row[x..x + width])
+    }
+
+    /// Returns an iterator over the cells of the view in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> {
+        self.rows().flatten()
+    }
+
+    /// Returns an iterator over every `(x, y)` position of the view, relative to its corner.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height()).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+}
+
+impl<T, const W: usize> Index<(usize, usize)> for GridView<'_, T, W> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        &self.row(y)[x]
+    }
+}
+
+/// A mutably borrowed rectangular region of a [`Grid`], indexed relative to its top-left corner.
+///
+/// Created by [`Grid::view_mut`].
+#[derive(Debug)]
+pub struct GridViewMut<'a, T, const W: usize> {
+    rows: &'a mut [[T; W]],
+    x: usize,
+    width: usize,
+}
+
+impl<T, const W: usize> GridViewMut<'_, T, W> {
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    pub const fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width {
+            self.rows.get(y).map(|row| &row[self.x + x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < self.width {
+            let start = self.x;
+            self.rows.get_mut(y).map(|row| &mut row[start + x])
+        } else {
+            None
+        }
+    }
+
+    /// Returns row `y` of the view.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `y >= height`.
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.rows[y][self.x..self.x + self.width]
+    }
+
+    /// Returns row `y` of the view mutably.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `y >= height`.
+    pub fn row_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.rows[y][self.x..self.x + self.width]
+    }
+
+    /// Returns a mutable iterator over the cells of the view in row-major order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        let (x, width) = (self.x, self.width);
+        self.rows
+            .iter_mut()
+            .flat_map(move |row| &mut row[x..x + width])
+    }
+
+    /// Sets every cell of the view to `value`.
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        for cell in self.iter_mut() {
+            *cell = value.clone();
+        }
+    }
+}
+
+impl<T, const W: usize> Index<(usize, usize)> for GridViewMut<'_, T, W> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        &self.row(y)[x]
+    }
+}
+
+impl<T, const W: usize> IndexMut<(usize, usize)> for GridViewMut<'_, T, W> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        &mut self.row_mut(y)[x]
+    }
+}
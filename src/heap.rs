@@ -0,0 +1,350 @@
+//! Fixed-capacity binary heap backed by an [`Array`].
+
+use core::{
+    fmt::{self, Debug},
+    mem,
+    ops::{Deref, DerefMut},
+    slice,
+};
+
+use crate::{Array, CapacityError};
+
+/// Fixed-capacity priority queue storing up to `L` elements inline as a binary max-heap.
+///
+/// `pop` returns the greatest element; wrap elements in [`core::cmp::Reverse`] for a min-heap.
+///
+/// # Examples
+///
+/// ```
+/// use arrayy::ArrayHeap;
+/// use core::cmp::Reverse;
+///
+/// let mut heap = ArrayHeap::<u32, 4>::new();
+/// heap.push(2);
+/// heap.push(5);
+/// heap.push(1);
+/// assert_eq!(heap.peek(), Some(&5));
+/// assert_eq!(heap.pop(), Some(5));
+///
+/// let mut min = ArrayHeap::<Reverse<u32>, 4>::new();
+/// min.push(Reverse(2));
+/// min.push(Reverse(1));
+/// assert_eq!(min.pop(), Some(Reverse(1)));
+/// ```
+pub struct ArrayHeap<T, const L: usize> {
+    data: Array<T, L>,
+}
+
+impl<T, const L: usize> Default for ArrayHeap<T, L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const L: usize> ArrayHeap<T, L> {
+    /// Creates an empty heap.
+    pub fn new() -> Self {
+        Self {
+            data: Array::default(),
+        }
+    }
+
+    pub const fn len(&self) -> usize {
+        self.data.len()
+    }
+
+    pub const fn capacity(&self) -> usize {
+        L
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    pub const fn is_full(&self) -> bool {
+        self.data.len() == L
+    }
+
+    /// Returns the greatest element, or `None` if the heap is empty.
+    pub fn peek(&self) -> Option<&T> {
+        self.data.first()
+    }
+
+    /// Returns the underlying elements in heap order.
+    pub fn as_slice(&self) -> &[T] {
+        self.data.as_slice()
+    }
+
+    /// Returns an iterator over the elements in heap (arbitrary) order.
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.data.as_slice().iter()
+    }
+
+    /// Consumes the heap, returning the underlying array in heap order.
+    pub fn into_array(self) -> Array<T, L> {
+        self.data
+    }
+
+    /// Clears the heap, removing all elements.
+    pub fn clear(&mut self) {
+        self.data.clear();
+    }
+}
+
+impl<T: Ord, const L: usize> ArrayHeap<T, L> {
+    fn sift_up(&mut self, mut pos: usize) {
+        let data = self.data.as_mut_slice();
+        while pos > 0 {
+            let parent = (pos - 1) / 2;
+            if data[pos] <= data[parent] {
+                break;
+            }
+            data.swap(pos, parent);
+            pos = parent;
+        }
+    }
+
+    fn sift_down(&mut self, pos: usize) {
+        let end = self.data.len();
+        sift_down_range(self.data.as_mut_slice(), pos, end);
+    }
+
+    /// Pushes an element onto the heap.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the heap is full.
+    pub fn push(&mut self, item: T) {
+        if let Err(err) = self.try_push(item) {
+            panic!("{}", err);
+        }
+    }
+
+    /// Pushes an element onto the heap, or returns it in a [`CapacityError`] if the heap is full.
+    pub fn try_push(&mut self, item: T) -> Result<(), CapacityError<T>> {
+        self.data.try_push(item)?;
+        self.sift_up(self.data.len() - 1);
+        Ok(())
+    }
+
+    /// Pushes an element, keeping the heap bounded: when full, the top of the heap is replaced if
+    /// `item` is less than it. Returns the element that was evicted or rejected, if any.
+    ///
+    /// With `Reverse` elements the top is the least element, so this keeps the `L` greatest
+    /// elements seen ("top-K").
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::ArrayHeap;
+    /// use core::cmp::Reverse;
+    ///
+    /// let mut top = ArrayHeap::<Reverse<u32>, 3>::new();
+    /// for score in [5, 1, 8, 3, 9, 2] {
+    ///     top.push_top_k(Reverse(score));
+    /// }
+    /// let best: Vec<u32> = top.into_sorted_array().into_iter().map(|r| r.0).collect();
+    /// assert_eq!(best, vec![9, 8, 5]);
+    /// ```
+    pub fn push_top_k(&mut self, item: T) -> Option<T> {
+        if !self.is_full() {
+            self.push(item);
+            return None;
+        }
+        match self.data.first_mut() {
+            Some(top) if item < *top => {
+                let old = mem::replace(top, item);
+                self.sift_down(0);
+                Some(old)
+            }
+            _ => Some(item),
+        }
+    }
+
+    /// Removes and returns the greatest element, or `None` if the heap is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        let len = self.data.len();
+        if len == 0 {
+            return None;
+        }
+        self.data.as_mut_slice().swap(0, len - 1);
+        let item = self.data.pop();
+        self.sift_down(0);
+        item
+    }
+
+    /// Returns a mutable reference to the greatest element, or `None` if the heap is empty. The
+    /// heap is restored when the returned guard is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::ArrayHeap;
+    /// let mut heap: ArrayHeap<u32, 4> = [1, 5, 2].into_iter().collect();
+    /// if let Some(mut top) = heap.peek_mut() {
+    ///     *top = 0;
+    /// }
+    /// assert_eq!(heap.peek(), Some(&2));
+    /// ```
+    pub fn peek_mut(&mut self) -> Option<PeekMut<'_, T, L>> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(PeekMut {
+                heap: self,
+                sift: false,
+            })
+        }
+    }
+
+    /// Consumes the heap, returning its elements in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::{array, ArrayHeap};
+    /// let heap: ArrayHeap<u32, 4> = [3, 1, 4, 1].into_iter().collect();
+    /// assert_eq!(heap.into_sorted_array(), array!(1, 1, 3, 4));
+    /// ```
+    pub fn into_sorted_array(mut self) -> Array<T, L> {
+        let data = self.data.as_mut_slice();
+        let mut end = data.len();
+        while end > 1 {
+            end -= 1;
+            data.swap(0, end);
+            sift_down_range(data, 0, end);
+        }
+        self.data
+    }
+}
+
+fn sift_down_range<T: Ord>(data: &mut [T], mut pos: usize, end: usize) {
+    loop {
+        let mut child = 2 * pos + 1;
+        if child >= end {
+            return;
+        }
+        if child + 1 < end && data[child] < data[child + 1] {
+            child += 1;
+        }
+        if data[pos] >= data[child] {
+            return;
+        }
+        data.swap(pos, child);
+        pos = child;
+    }
+}
+
+/// A mutable reference to the greatest element of an [`ArrayHeap`].
+///
+/// Created by [`ArrayHeap::peek_mut`]. The heap is restored when this is dropped.
+pub struct PeekMut<'a, T: Ord, const L: usize> {
+    heap: &'a mut ArrayHeap<T, L>,
+    sift: bool,
+}
+
+impl<T: Ord, const L: usize> PeekMut<'_, T, L> {
+    /// Removes the peeked element from the heap and returns it.
+    pub fn pop(mut this: Self) -> T {
+        this.sift = false;
+        this.heap.pop().unwrap()
+    }
+}
+
+impl<T: Ord, const L: usize> Deref for PeekMut<'_, T, L> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        unsafe { self.heap.data.get_unchecked(0) }
+    }
+}
+
+impl<T: Ord, const L: usize> DerefMut for PeekMut<'_, T, L> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.sift = true;
+        unsafe { self.heap.data.get_unchecked_mut(0) }
+    }
+}
+
+impl<T: Ord, const L: usize> Drop for PeekMut<'_, T, L> {
+    fn drop(&mut self) {
+        if self.sift {
+            self.heap.sift_down(0);
+        }
+    }
+}
+
+impl<T: Ord + Debug, const L: usize> Debug for PeekMut<'_, T, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PeekMut").field(&**self).finish()
+    }
+}
+
+impl<T: Ord, const L: usize> From<Array<T, L>> for ArrayHeap<T, L> {
+    /// Builds a heap from an array in O(n).
+    fn from(data: Array<T, L>) -> Self {
+        let mut heap = Self { data };
+        let len = heap.len();
+        for pos in (0..len / 2).rev() {
+            heap.sift_down(pos);
+        }
+        heap
+    }
+}
+
+impl<T, const L: usize> IntoIterator for ArrayHeap<T, L> {
+    type Item = T;
+    type IntoIter = crate::IntoIter<T, L>;
+
+    /// Creates an iterator that moves the elements out in heap (arbitrary) order.
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, T, const L: usize> IntoIterator for &'a ArrayHeap<T, L> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: Ord, const L: usize> FromIterator<T> for ArrayHeap<T, L> {
+    /// Creates a heap from an iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator yields more than `L` elements.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<Array<T, L>>())
+    }
+}
+
+impl<T: Ord, const L: usize> Extend<T> for ArrayHeap<T, L> {
+    /// Pushes the elements of an iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the heap overflows.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+impl<T: Clone, const L: usize> Clone for ArrayHeap<T, L> {
+    fn clone(&self) -> Self {
+        Self {
+            data: self.data.clone(),
+        }
+    }
+}
+
+impl<T: Debug, const L: usize> Debug for ArrayHeap<T, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
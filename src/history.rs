@@ -0,0 +1,213 @@
+use core::{
+    cmp::Ordering,
+    fmt::{self, Debug},
+    iter::Sum,
+};
+
+use crate::ArrayDeque;
+
+/// Fixed-capacity history of the last `L` values pushed, stored inline in a ring buffer.
+///
+/// Pushing never fails: once full, each push overwrites the oldest value in O(1). Values are
+/// indexed and iterated from oldest to newest.
+///
+/// # Examples
+///
+/// ```
+/// use arrayy::RingHistory;
+///
+/// let mut samples = RingHistory::<u32, 3>::new();
+/// for sample in [10, 20, 30, 40] {
+///     samples.push(sample);
+/// }
+/// assert_eq!(samples.iter().copied().collect::<Vec<_>>(), vec![20, 30, 40]);
+/// assert_eq!(samples.latest(2).copied().collect::<Vec<_>>(), vec![30, 40]);
+/// assert_eq!(samples.mean(), Some(30.0));
+/// ```
+#[derive(Clone, PartialEq, Eq)]
+pub struct RingHistory<T, const L: usize> {
+    buf: ArrayDeque<T, L>,
+}
+
+impl<T, const L: usize> Default for RingHistory<T, L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const L: usize> RingHistory<T, L> {
+    /// Creates an empty history.
+    pub const fn new() -> Self {
+        Self {
+            buf: ArrayDeque::new_overwrite(),
+        }
+    }
+
+    pub const fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    pub const fn capacity(&self) -> usize {
+        L
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    pub const fn is_full(&self) -> bool {
+        self.buf.is_full()
+    }
+
+    /// Records a value, returning the oldest value if it was overwritten.
+    pub fn push(&mut self, val: T) -> Option<T> {
+        self.buf.push_back_overwrite(val)
+    }
+
+    /// Returns the value at `index`, counting from the oldest.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.buf.get(index)
+    }
+
+    pub fn oldest(&self) -> Option<&T> {
+        self.buf.front()
+    }
+
+    pub fn newest(&self) -> Option<&T> {
+        self.buf.back()
+    }
+
+    /// Returns the values as two slices, oldest first; their concatenation is the history.
+    pub fn as_slices(&self) -> (&[T], &[T]) {
+        self.buf.as_slices()
+    }
+
+    /// Returns an iterator over the values from oldest to newest.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> {
+        self.buf.iter()
+    }
+
+    /// Returns an iterator over the `n` newest values, from oldest to newest. Yields the whole
+    /// history if it holds fewer than `n` values.
+    pub fn latest(&self, n: usize) -> impl DoubleEndedIterator<Item = &T> {
+        let (a, b) = self.buf.as_slices();
+        let n = n.min(a.len() + b.len());
+        let (a, b) = if n <= b.len() {
+            (&a[a.len()..], &b[b.len() - n..])
+        } else {
+            (&a[a.len() + b.len() - n..], b)
+        };
+        a.iter().chain(b)
+    }
+
+    /// Removes all values.
+    pub fn clear(&mut self) {
+        self.buf.clear();
+    }
+}
+
+impl<T: Copy + Sum, const L: usize> RingHistory<T, L> {
+    /// Returns the sum of the values, or zero if the history is empty.
+    pub fn sum(&self) -> T {
+        self.iter().copied().sum()
+    }
+}
+
+/// Lossy conversion to `f64` for the primitive numeric types, used by [`RingHistory::mean`].
+///
+/// Unlike `Into<f64>` it also covers the 64- and 128-bit and pointer-sized integers. Implement
+/// it for your own numeric types to use them with `mean`.
+///
+/// # Examples
+///
+/// ```
+/// use arrayy::{AsF64, RingHistory};
+///
+/// #[derive(Clone, Copy)]
+/// struct Millis(u64);
+///
+/// impl AsF64 for Millis {
+///     fn as_f64(self) -> f64 {
+///         self.0 as f64
+///     }
+/// }
+///
+/// let latencies: RingHistory<Millis, 4> = [Millis(10), Millis(30)].into_iter().collect();
+/// assert_eq!(latencies.mean(), Some(20.0));
+/// ```
+pub trait AsF64: Copy {
+    /// Converts the value to the nearest `f64`, like an `as f64` cast.
+    fn as_f64(self) -> f64;
+}
+
+macro_rules! impl_as_f64 {
+    ($($t:ty)*) => {
+        $(
+            impl AsF64 for $t {
+                fn as_f64(self) -> f64 {
+                    self as f64
+                }
+            }
+        )*
+    };
+}
+
+impl_as_f64!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize f32 f64);
+
+impl<T: AsF64, const L: usize> RingHistory<T, L> {
+    /// Returns the arithmetic mean of the values, or `None` if the history is empty.
+    pub fn mean(&self) -> Option<f64> {
+        if self.is_empty() {
+            return None;
+        }
+        let sum: f64 = self.iter().map(|&x| x.as_f64()).sum();
+        Some(sum / self.len() as f64)
+    }
+}
+
+impl<T: PartialOrd, const L: usize> RingHistory<T, L> {
+    /// Picks the value that compares as `ordering` against all others, skipping values that do
+    /// not compare with themselves (such as NaN) unless there is nothing else.
+    fn extreme(&self, ordering: Ordering) -> Option<&T> {
+        self.iter().fold(None, |best, x| match best {
+            Some(b) if b.partial_cmp(b).is_some() && x.partial_cmp(b) != Some(ordering) => Some(b),
+            _ => Some(x),
+        })
+    }
+
+    /// Returns the smallest value, or `None` if the history is empty. NaN-like values that do
+    /// not compare with themselves are ignored unless every value is one.
+    pub fn min(&self) -> Option<&T> {
+        self.extreme(Ordering::Less)
+    }
+
+    /// Returns the largest value, or `None` if the history is empty. NaN-like values that do not
+    /// compare with themselves are ignored unless every value is one.
+    pub fn max(&self) -> Option<&T> {
+        self.extreme(Ordering::Greater)
+    }
+}
+
+impl<T, const L: usize> Extend<T> for RingHistory<T, L> {
+    /// Records the values of an iterator, keeping only the last `L`.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for val in iter {
+            self.push(val);
+        }
+    }
+}
+
+impl<T, const L: usize> FromIterator<T> for RingHistory<T, L> {
+    /// Creates a history holding the last `L` values of an iterator.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut history = Self::new();
+        history.extend(iter);
+        history
+    }
+}
+
+impl<T: Debug, const L: usize> Debug for RingHistory<T, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
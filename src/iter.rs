@@ -0,0 +1,355 @@
+use core::{
+    fmt::{self, Debug},
+    iter::FusedIterator,
+    ops::Range,
+    ptr, slice,
+};
+
+use crate::Array;
+
+/// An iterator that moves out of an [`Array`].
+///
+/// Created by the `into_iter` method on `Array` (provided by the [`IntoIterator`] trait).
+///
+/// # Examples
+///
+/// ```
+/// use arrayy::array;
+/// let (a, b) = (String::from("a"), String::from("b"));
+/// let arr = array!(a, b);
+/// let mut iter = arr.into_iter();
+/// assert_eq!(iter.as_slice(), &["a", "b"]);
+/// assert_eq!(iter.next_back(), Some(String::from("b")));
+/// assert_eq!(iter.next(), Some(String::from("a")));
+/// assert_eq!(iter.next(), None);
+/// ```
+pub struct IntoIter<T, const L: usize> {
+    // `arr.len` is kept at 0 so that only `start..end` is dropped by `IntoIter`.
+    arr: Array<T, L>,
+    start: usize,
+    end: usize,
+}
+
+impl<T, const L: usize> IntoIter<T, L> {
+    /// Returns the remaining elements as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.arr.as_ptr().add(self.start), self.len()) }
+    }
+
+    /// Returns the remaining elements as a mutable slice.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        let len = self.len();
+        unsafe { slice::from_raw_parts_mut(self.arr.as_mut_ptr().add(self.start), len) }
+    }
+}
+
+impl<T, const L: usize> IntoIterator for Array<T, L> {
+    type Item = T;
+    type IntoIter = IntoIter<T, L>;
+
+    /// Creates an iterator that moves each element out of the array, from front to back.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::array;
+    /// let arr = array!(1, 2, 3; 5);
+    /// let v: Vec<i32> = arr.into_iter().rev().collect();
+    /// assert_eq!(v, vec![3, 2, 1]);
+    /// ```
+    fn into_iter(mut self) -> Self::IntoIter {
+        let end = self.len;
+        self.len = 0;
+        IntoIter {
+            arr: self,
+            start: 0,
+            end,
+        }
+    }
+}
+
+impl<'a, T, const L: usize> IntoIterator for &'a Array<T, L> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_slice().iter()
+    }
+}
+
+impl<'a, T, const L: usize> IntoIterator for &'a mut Array<T, L> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_mut_slice().iter_mut()
+    }
+}
+
+impl<T, const L: usize> FromIterator<T> for Array<T, L> {
+    /// Creates an array from an iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator yields more than `L` elements. See [`Array::try_from_iter`] and
+    /// [`Array::from_iter_truncated`] for non-panicking alternatives.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::{array, Array};
+    /// let arr: Array<i32, 5> = (1..4).map(|x| x * 2).collect();
+    /// assert_eq!(arr, array!(2, 4, 6));
+    /// ```
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut arr = Array::default();
+        arr.extend(iter);
+        arr
+    }
+}
+
+impl<T, const L: usize> Extend<T> for Array<T, L> {
+    /// Appends the elements of an iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array overflows. See [`Array::try_extend`] and
+    /// [`Array::extend_truncated`] for non-panicking alternatives.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for elt in iter {
+            self.push(elt);
+        }
+    }
+}
+
+impl<'a, T: Copy + 'a, const L: usize> Extend<&'a T> for Array<T, L> {
+    /// Appends copies of the elements of an iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array overflows.
+    fn extend<I: IntoIterator<Item = &'a T>>(&mut self, iter: I) {
+        self.extend(iter.into_iter().copied());
+    }
+}
+
+impl<T, const L: usize> Iterator for IntoIter<T, L> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+        let idx = self.start;
+        self.start += 1;
+        Some(unsafe { self.arr.data.get_unchecked(idx).assume_init_read() })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+        (len, Some(len))
+    }
+
+    fn count(self) -> usize {
+        self.len()
+    }
+}
+
+impl<T, const L: usize> DoubleEndedIterator for IntoIter<T, L> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+        self.end -= 1;
+        Some(unsafe { self.arr.data.get_unchecked(self.end).assume_init_read() })
+    }
+}
+
+impl<T, const L: usize> ExactSizeIterator for IntoIter<T, L> {}
+
+impl<T, const L: usize> FusedIterator for IntoIter<T, L> {}
+
+impl<T: Clone, const L: usize> Clone for IntoIter<T, L> {
+    fn clone(&self) -> Self {
+        Array::<T, L>::from(self.as_slice()).into_iter()
+    }
+}
+
+impl<T: Debug, const L: usize> Debug for IntoIter<T, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("IntoIter").field(&self.as_slice()).finish()
+    }
+}
+
+impl<T, const L: usize> Drop for IntoIter<T, L> {
+    fn drop(&mut self) {
+        unsafe { ptr::drop_in_place(self.as_mut_slice()) };
+    }
+}
+
+/// A draining iterator over a range of an [`Array`].
+///
+/// Created by [`Array::drain`].
+pub struct Drain<'a, T, const L: usize> {
+    // `arr.len` is kept at the start of the drained range until the iterator is dropped.
+    arr: &'a mut Array<T, L>,
+    start: usize,
+    end: usize,
+    tail_start: usize,
+    tail_len: usize,
+}
+
+impl<'a, T, const L: usize> Drain<'a, T, L> {
+    pub(crate) fn new(arr: &'a mut Array<T, L>, range: Range<usize>) -> Self {
+        let len = arr.len;
+        arr.len = range.start;
+        Self {
+            arr,
+            start: range.start,
+            end: range.end,
+            tail_start: range.end,
+            tail_len: len - range.end,
+        }
+    }
+
+    /// Returns the remaining drained elements as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        unsafe { slice::from_raw_parts(self.arr.as_ptr().add(self.start), self.len()) }
+    }
+}
+
+impl<T, const L: usize> Iterator for Drain<'_, T, L> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+        let idx = self.start;
+        self.start += 1;
+        Some(unsafe { self.arr.data.get_unchecked(idx).assume_init_read() })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+        (len, Some(len))
+    }
+}
+
+impl<T, const L: usize> DoubleEndedIterator for Drain<'_, T, L> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start == self.end {
+            return None;
+        }
+        self.end -= 1;
+        Some(unsafe { self.arr.data.get_unchecked(self.end).assume_init_read() })
+    }
+}
+
+impl<T, const L: usize> ExactSizeIterator for Drain<'_, T, L> {}
+
+impl<T, const L: usize> FusedIterator for Drain<'_, T, L> {}
+
+impl<T: Debug, const L: usize> Debug for Drain<'_, T, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Drain").field(&self.as_slice()).finish()
+    }
+}
+
+impl<T, const L: usize> Drop for Drain<'_, T, L> {
+    fn drop(&mut self) {
+        unsafe {
+            let p = self.arr.as_mut_ptr();
+            let rest = ptr::slice_from_raw_parts_mut(p.add(self.start), self.end - self.start);
+            self.start = self.end;
+            ptr::drop_in_place(rest);
+            let len = self.arr.len;
+            if self.tail_start != len {
+                ptr::copy(p.add(self.tail_start), p.add(len), self.tail_len);
+            }
+            self.arr.len = len + self.tail_len;
+        }
+    }
+}
+
+/// An iterator that removes the elements of an [`Array`] matching a predicate.
+///
+/// Created by [`Array::extract_if`].
+pub struct ExtractIf<'a, T, F, const L: usize>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    // `arr.len` is kept at 0 until the iterator is dropped.
+    arr: &'a mut Array<T, L>,
+    pred: F,
+    idx: usize,
+    end: usize,
+    del: usize,
+    old_len: usize,
+}
+
+impl<'a, T, F, const L: usize> ExtractIf<'a, T, F, L>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    pub(crate) fn new(arr: &'a mut Array<T, L>, range: Range<usize>, pred: F) -> Self {
+        let old_len = arr.len;
+        arr.len = 0;
+        Self {
+            arr,
+            pred,
+            idx: range.start,
+            end: range.end,
+            del: 0,
+            old_len,
+        }
+    }
+}
+
+impl<T, F, const L: usize> Iterator for ExtractIf<'_, T, F, L>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        unsafe {
+            while self.idx < self.end {
+                let i = self.idx;
+                let p = self.arr.as_mut_ptr();
+                let matched = (self.pred)(&mut *p.add(i));
+                self.idx += 1;
+                if matched {
+                    self.del += 1;
+                    return Some(ptr::read(p.add(i)));
+                } else if self.del > 0 {
+                    ptr::copy_nonoverlapping(p.add(i), p.add(i - self.del), 1);
+                }
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.end - self.idx))
+    }
+}
+
+impl<T, F, const L: usize> Drop for ExtractIf<'_, T, F, L>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    fn drop(&mut self) {
+        unsafe {
+            if self.idx < self.old_len && self.del > 0 {
+                let p = self.arr.as_mut_ptr();
+                ptr::copy(
+                    p.add(self.idx),
+                    p.add(self.idx - self.del),
+                    self.old_len - self.idx,
+                );
+            }
+            self.arr.len = self.old_len - self.del;
+        }
+    }
+}
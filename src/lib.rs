@@ -1,29 +1,145 @@
-use std::{
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(test)]
+extern crate std;
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::{
+    borrow::{Borrow, BorrowMut},
+    cmp::Ordering,
     fmt::Debug,
-    mem,
-    ops::{Deref, DerefMut, Range},
+    hash::{Hash, Hasher},
+    mem::{ManuallyDrop, MaybeUninit},
+    ops::{Bound, Deref, DerefMut, Range, RangeBounds},
     ptr::{self},
 };
 
+pub mod arena;
+mod atomic;
+mod bits;
+pub mod deque;
+mod error;
+pub mod grid;
+pub mod heap;
+mod history;
+mod iter;
+pub mod lru;
+pub mod map;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod set;
+pub mod slab;
+#[cfg(feature = "alloc")]
+pub mod small;
+mod sorted;
+mod sparse;
+pub mod spsc;
+mod string;
 mod tests;
 
-#[derive(Clone, Copy)]
+pub use arena::ArrayArena;
+pub use atomic::AtomicArray;
+pub use bits::{words_for, BitArray};
+pub use deque::ArrayDeque;
+pub use error::CapacityError;
+pub use grid::Grid;
+pub use heap::ArrayHeap;
+pub use history::{AsF64, RingHistory};
+pub use iter::{Drain, ExtractIf, IntoIter};
+pub use lru::ArrayLru;
+pub use map::ArrayMap;
+pub use set::ArraySet;
+pub use slab::ArraySlab;
+#[cfg(feature = "alloc")]
+pub use small::SmallArray;
+pub use sorted::SortedArray;
+pub use sparse::{SparseMap, SparseSet};
+pub use string::ArrayString;
+
+/// Fixed-capacity array storing up to `L` elements inline.
+///
+/// Only the first `len` slots of the buffer are initialized; the rest are left uninitialized,
+/// so `T` does not need to implement `Copy` or `Default`.
 pub struct Array<T, const L: usize> {
-    data: [T; L],
+    data: [MaybeUninit<T>; L],
     len: usize,
 }
 
-impl<T: Default + Copy, const L: usize> Default for Array<T, L> {
+impl<T, const L: usize> Default for Array<T, L> {
     fn default() -> Self {
         Self {
-            data: [T::default(); L],
+            data: uninit_buf(),
             len: 0,
         }
     }
 }
 
-impl<T: Copy + Default, const L: usize> Array<T, L> {
+const fn uninit_buf<T, const L: usize>() -> [MaybeUninit<T>; L] {
+    // An array of `MaybeUninit` does not require initialization.
+    unsafe { MaybeUninit::<[MaybeUninit<T>; L]>::uninit().assume_init() }
+}
+
+/// Reinterprets initialized elements as `MaybeUninit` slots, which `const fn`s cannot do through
+/// `ptr::read` or `transmute` on generic arrays.
+union Init<T, const L: usize> {
+    init: ManuallyDrop<[T; L]>,
+    uninit: ManuallyDrop<[MaybeUninit<T>; L]>,
+}
+
+/// Capacity relations checked when a function using them is monomorphized, so violations are
+/// reported at compile time.
+struct CapacityCheck<const A: usize, const B: usize, const C: usize>;
+
+impl<const A: usize, const B: usize, const C: usize> CapacityCheck<A, B, C> {
+    /// `A + B <= C`
+    const SUM_FITS: () = assert!(A + B <= C, "combined capacity exceeds target capacity");
+    /// `A <= C <= A + B`
+    const SPLIT_FITS: () = assert!(
+        A <= C && C <= A + B,
+        "split capacities do not cover source capacity"
+    );
+}
+
+/// Panics with `"{name} ({value}) >= {limit_name} ({limit})"` unless `value < limit`.
+fn check_below(name: &str, value: usize, limit_name: &str, limit: usize) {
+    if value >= limit {
+        panic!("{} ({}) >= {} ({})", name, value, limit_name, limit);
+    }
+}
+
+fn range_of<R: RangeBounds<usize>>(range: R, len: usize) -> Range<usize> {
+    let start = match range.start_bound() {
+        Bound::Included(&n) => n,
+        Bound::Excluded(&n) => n.checked_add(1).expect("range start overflows usize"),
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&n) => n.checked_add(1).expect("range end overflows usize"),
+        Bound::Excluded(&n) => n,
+        Bound::Unbounded => len,
+    };
+    if start > end {
+        panic!("range start ({}) > end ({})", start, end);
+    }
+    if end > len {
+        panic!("range end ({}) > len ({})", end, len);
+    }
+    start..end
+}
+
+impl<T, const L: usize> Array<T, L> {
     pub const fn new(data: [T; L]) -> Self {
+        let data = unsafe {
+            ManuallyDrop::into_inner(
+                Init {
+                    init: ManuallyDrop::new(data),
+                }
+                .uninit,
+            )
+        };
         let len = L;
         Self { data, len }
     }
@@ -44,13 +160,17 @@ impl<T: Copy + Default, const L: usize> Array<T, L> {
     ///
     /// # Safety
     ///
-    /// The caller must ensure that `len` is less than or equal to the capacity of the array.
+    /// The caller must ensure that the first `len` elements are initialized.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is greater than the capacity of the array.
     ///
     /// # Examples
     ///
     /// ```
     /// use arrayy::array;
-    /// let mut arr = array!(i32; 5);
+    /// let mut arr = array!(1, 2, 3, 4, 5);
     /// unsafe { arr.set_len(3) };
     /// assert_eq!(arr.len(), 3);
     /// ```
@@ -61,34 +181,41 @@ impl<T: Copy + Default, const L: usize> Array<T, L> {
         self.len = len;
     }
 
+    /// Sets the length of the array without checking it against the capacity.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `len` is less than or equal to the capacity of the array and
+    /// that the first `len` elements are initialized.
     pub unsafe fn set_len_unchecked(&mut self, len: usize) {
         self.len = len;
     }
 
-    /// Returns a reference to the underlying buffer.
+    /// Returns a reference to the underlying buffer. Only the first `len` slots are initialized.
     ///
     /// # Examples
     ///
     /// ```
     /// use arrayy::array;
     /// let arr = array!(1, 2, 3; 5);
-    /// unsafe { assert_eq!(arr.buf(), &[1, 2, 3, 0, 0]) };
+    /// assert_eq!(unsafe { arr.buf()[2].assume_init() }, 3);
     /// ```
-    pub const fn buf(&self) -> &[T; L] {
+    pub const fn buf(&self) -> &[MaybeUninit<T>; L] {
         &self.data
     }
 
-    /// Returns a mutable reference to the underlying buffer.
+    /// Returns a mutable reference to the underlying buffer. Only the first `len` slots are initialized.
     ///
     /// # Examples
     ///
     /// ```
     /// use arrayy::array;
     /// let mut arr = array!(i32; 5);
-    /// unsafe { arr.buf_mut()[0] = 1 };
-    /// assert_eq!(unsafe { arr.buf() }, &[1, 0, 0, 0, 0]);
+    /// arr.buf_mut()[0].write(1);
+    /// unsafe { arr.set_len(1) };
+    /// assert_eq!(arr, array!(1));
     /// ```
-    pub fn buf_mut(&mut self) -> &mut [T; L] {
+    pub fn buf_mut(&mut self) -> &mut [MaybeUninit<T>; L] {
         &mut self.data
     }
 
@@ -132,7 +259,7 @@ impl<T: Copy + Default, const L: usize> Array<T, L> {
         if index >= self.len {
             None
         } else {
-            Some(unsafe { self.data.get_unchecked(index) })
+            Some(unsafe { self.get_unchecked(index) })
         }
     }
 
@@ -152,7 +279,7 @@ impl<T: Copy + Default, const L: usize> Array<T, L> {
         if index >= self.len {
             None
         } else {
-            Some(unsafe { self.data.get_unchecked_mut(index) })
+            Some(unsafe { self.get_unchecked_mut(index) })
         }
     }
 
@@ -170,11 +297,16 @@ impl<T: Copy + Default, const L: usize> Array<T, L> {
     /// unsafe { assert_eq!(arr.get_unchecked(1), &2) };
     /// ```
     pub unsafe fn get_unchecked(&self, index: usize) -> &T {
-        self.data.get_unchecked(index)
+        self.data.get_unchecked(index).assume_init_ref()
     }
 
+    /// Returns a mutable reference to the element at the specified index without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `index` is within bounds.
     pub unsafe fn get_unchecked_mut(&mut self, index: usize) -> &mut T {
-        self.data.get_unchecked_mut(index)
+        self.data.get_unchecked_mut(index).assume_init_mut()
     }
 
     pub fn last(&self) -> Option<&T> {
@@ -201,85 +333,166 @@ impl<T: Copy + Default, const L: usize> Array<T, L> {
         self.get_mut(0)
     }
 
-    pub fn from(arr: &[T]) -> Self {
-        let mut s = Self {
-            data: [T::default(); L],
-            len: arr.len(),
-        };
-        s.data[0..arr.len()].copy_from_slice(arr);
-        s
+    /// Appends an element to the back of the array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array is at full capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::array;
+    /// let mut arr = array!(1, 2; 3);
+    /// arr.push(3);
+    /// assert_eq!(arr, array!(1, 2, 3));
+    /// ```
+    pub fn push(&mut self, val: T) {
+        if self.len == L {
+            panic!("len ({}) == capacity ({})", self.len, L);
+        }
+        unsafe { self.push_unchecked(val) };
     }
 
-    pub fn push(&mut self, val: T) {
-        self.data[self.len] = val;
-        self.len += 1;
+    /// Appends an element to the back of the array, or returns it in a [`CapacityError`] if the
+    /// array is at full capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::array;
+    /// let mut arr = array!(1, 2; 3);
+    /// assert!(arr.try_push(3).is_ok());
+    /// assert_eq!(arr.try_push(4).unwrap_err().element(), 4);
+    /// assert_eq!(arr, array!(1, 2, 3));
+    /// ```
+    pub fn try_push(&mut self, val: T) -> Result<(), CapacityError<T>> {
+        if self.len == L {
+            return Err(CapacityError::new(val, self.len, L));
+        }
+        unsafe { self.push_unchecked(val) };
+        Ok(())
     }
 
+    /// Appends an element to the back of the array without checking the capacity.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the array is not at full capacity.
     pub unsafe fn push_unchecked(&mut self, val: T) {
-        *self.data.get_unchecked_mut(self.len) = val;
+        self.data.get_unchecked_mut(self.len).write(val);
         self.len += 1;
     }
 
+    /// Removes the last element from the array and returns it, or `None` if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::array;
+    /// let (a, b) = (String::from("a"), String::from("b"));
+    /// let mut arr = array!(a, b);
+    /// assert_eq!(arr.pop(), Some(String::from("b")));
+    /// assert_eq!(arr.len(), 1);
+    /// ```
     pub fn pop(&mut self) -> Option<T> {
         if self.len == 0 {
             return None;
         }
-        self.len -= 1;
-        Some(mem::take(self.data.get_mut(self.len).unwrap()))
+        Some(unsafe { self.pop_unchecked() })
     }
 
+    /// Removes the last element from the array and returns it without checking the length.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the array is not empty.
     pub unsafe fn pop_unchecked(&mut self) -> T {
         self.len -= 1;
-        *self.data.get_unchecked(self.len)
+        self.data.get_unchecked(self.len).assume_init_read()
     }
 
     pub fn iter(&self) -> impl Iterator<Item = &T> {
-        self.data[0..self.len].iter()
+        self.as_slice().iter()
     }
 
     pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
-        self.data[0..self.len].iter_mut()
+        self.as_mut_slice().iter_mut()
     }
 
-    /// Appends the elements of another array to the end of this array.
-    ///
-    /// # Panics
+    /// Appends the elements of an iterator until the array is full, returning the first element
+    /// that did not fit in a [`CapacityError`].
     ///
-    /// Panics if the combined length of both arrays exceeds the capacity of this array.
+    /// Elements pushed before the overflow are kept. The iterator is not advanced past the rejected
+    /// element, so passing `iter.by_ref()` lets the caller recover the rest.
     ///
     /// # Examples
     ///
     /// ```
     /// use arrayy::array;
-    /// let mut arr1 = array!(1, 2, 3; 5);
-    /// let arr2 = array!(4, 5);
-    /// arr1.append(&arr2);
-    /// assert_eq!(arr1, array!(1, 2, 3, 4, 5));
+    /// let mut arr = array!(1,; 3);
+    /// let mut iter = 2..6;
+    /// let err = arr.try_extend(iter.by_ref()).unwrap_err();
+    /// assert_eq!(err.element(), 4);
+    /// assert_eq!(arr, array!(1, 2, 3));
+    /// assert_eq!(iter.next(), Some(5));
     /// ```
-    pub fn append<const M: usize>(&mut self, other: &Array<T, M>) {
-        if other.len > L - self.len {
-            panic!()
+    pub fn try_extend<I: IntoIterator<Item = T>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), CapacityError<T>> {
+        for elt in iter {
+            self.try_push(elt)?;
         }
-        self.data[self.len..self.len + other.len].copy_from_slice(&other.data[0..other.len]);
-        self.len += other.len;
+        Ok(())
     }
 
-    pub unsafe fn append_unchecked<const M: usize>(&mut self, other: &Array<T, M>) {
-        self.data[self.len..self.len + other.len].copy_from_slice(&other.data[0..other.len]);
-        self.len += other.len;
+    /// Appends the elements of an iterator until the array is full, ignoring the rest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::array;
+    /// let mut arr = array!(1,; 3);
+    /// arr.extend_truncated(2..10);
+    /// assert_eq!(arr, array!(1, 2, 3));
+    /// ```
+    pub fn extend_truncated<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let remaining = L - self.len;
+        for elt in iter.into_iter().take(remaining) {
+            unsafe { self.push_unchecked(elt) };
+        }
     }
 
-    pub fn append_slice(&mut self, other: &[T]) {
-        if other.len() > L - self.len {
-            panic!()
-        }
-        self.data[self.len..self.len + other.len()].copy_from_slice(other);
-        self.len += other.len();
+    /// Creates an array from an iterator, or returns the first element that did not fit in a
+    /// [`CapacityError`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::Array;
+    /// assert!(Array::<i32, 3>::try_from_iter(1..4).is_ok());
+    /// assert_eq!(Array::<i32, 3>::try_from_iter(1..5).unwrap_err().element(), 4);
+    /// ```
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self, CapacityError<T>> {
+        let mut arr = Self::default();
+        arr.try_extend(iter)?;
+        Ok(arr)
     }
 
-    pub unsafe fn append_slice_unchecked(&mut self, other: &[T]) {
-        self.data[self.len..self.len + other.len()].copy_from_slice(other);
-        self.len += other.len();
+    /// Creates an array from the first `L` elements of an iterator, ignoring the rest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::{array, Array};
+    /// let arr = Array::<i32, 3>::from_iter_truncated(1..10);
+    /// assert_eq!(arr, array!(1, 2, 3));
+    /// ```
+    pub fn from_iter_truncated<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut arr = Self::default();
+        arr.extend_truncated(iter);
+        arr
     }
 
     /// Filters the elements of the array, returning a new array with only the elements that match the predicate.
@@ -293,18 +506,106 @@ impl<T: Copy + Default, const L: usize> Array<T, L> {
     /// assert_eq!(filtered, array!(2, 4));
     /// ```
     pub fn filter(mut self, mut predicate: impl FnMut(&T) -> bool) -> Self {
+        self.retain(|elt| predicate(elt));
+        self
+    }
+
+    /// Retains only the elements that match the predicate, removing the rest in place.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::array;
+    /// let mut arr = array!(1, 2, 3, 4, 5);
+    /// arr.retain(|&x| x % 2 == 1);
+    /// assert_eq!(arr, array!(1, 3, 5));
+    /// ```
+    pub fn retain(&mut self, mut f: impl FnMut(&T) -> bool) {
+        self.retain_mut(|elt| f(elt));
+    }
+
+    /// Retains only the elements that match the predicate, passing a mutable reference to each element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::array;
+    /// let mut arr = array!(1, 2, 3, 4);
+    /// arr.retain_mut(|x| {
+    ///     *x *= 10;
+    ///     *x > 20
+    /// });
+    /// assert_eq!(arr, array!(30, 40));
+    /// ```
+    pub fn retain_mut(&mut self, mut f: impl FnMut(&mut T) -> bool) {
+        let len = self.len;
+        // Elements are leaked rather than double-dropped if `f` panics.
+        self.len = 0;
         let mut j = 0;
-        for i in 0..self.len {
+        for i in 0..len {
             unsafe {
-                let elt = *self.data.get_unchecked(i);
-                if predicate(&elt) {
-                    *self.data.get_unchecked_mut(j) = elt;
+                let p = self.as_mut_ptr();
+                if f(&mut *p.add(i)) {
+                    if i != j {
+                        ptr::copy_nonoverlapping(p.add(i), p.add(j), 1);
+                    }
                     j += 1;
+                } else {
+                    ptr::drop_in_place(p.add(i));
                 }
             }
         }
         self.len = j;
-        self
+    }
+
+    /// Removes consecutive elements for which `same_bucket` returns `true`, keeping the first of each run.
+    ///
+    /// `same_bucket` is called with the current element and the last retained element, in that order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::array;
+    /// let mut arr = array!(1, 11, 2, 12, 22, 3);
+    /// arr.dedup_by(|a, b| *a % 10 == *b % 10);
+    /// assert_eq!(arr, array!(1, 2, 3));
+    /// ```
+    pub fn dedup_by(&mut self, mut same_bucket: impl FnMut(&mut T, &mut T) -> bool) {
+        let len = self.len;
+        if len <= 1 {
+            return;
+        }
+        // Elements are leaked rather than double-dropped if `same_bucket` panics.
+        self.len = 0;
+        let mut j = 1;
+        for i in 1..len {
+            unsafe {
+                let p = self.as_mut_ptr();
+                if same_bucket(&mut *p.add(i), &mut *p.add(j - 1)) {
+                    ptr::drop_in_place(p.add(i));
+                } else {
+                    if i != j {
+                        ptr::copy_nonoverlapping(p.add(i), p.add(j), 1);
+                    }
+                    j += 1;
+                }
+            }
+        }
+        self.len = j;
+    }
+
+    /// Removes consecutive elements that map to the same key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::array;
+    /// let mut arr = array!(10, 11, 20, 30, 31);
+    /// arr.dedup_by_key(|x| *x / 10);
+    /// assert_eq!(arr, array!(10, 20, 30));
+    /// ```
+    pub fn dedup_by_key<K: PartialEq>(&mut self, mut key: impl FnMut(&mut T) -> K) {
+        self.dedup_by(|a, b| key(a) == key(b));
     }
 
     /// Applies a function to each element in the array, returning a new array with the results.
@@ -317,15 +618,17 @@ impl<T: Copy + Default, const L: usize> Array<T, L> {
     /// let mapped = arr.map(|&x| x * 2);
     /// assert_eq!(mapped, array!(2, 4, 6));
     /// ```
-    pub fn map<X: Copy + Default>(self, mut f: impl FnMut(&T) -> X) -> Array<X, L> {
+    pub fn map<X>(mut self, mut f: impl FnMut(&T) -> X) -> Array<X, L> {
+        let len = self.len;
+        // Elements are leaked rather than double-dropped if `f` panics.
+        self.len = 0;
         let mut copy = Array::<X, L>::default();
-        for i in 0..self.len {
+        for i in 0..len {
             unsafe {
-                let elt = *self.data.get_unchecked(i);
-                *copy.get_unchecked_mut(i) = f(&elt);
+                let elt = self.data.get_unchecked(i).assume_init_read();
+                copy.push_unchecked(f(&elt));
             }
         }
-        copy.len = self.len;
         copy
     }
 
@@ -340,22 +643,23 @@ impl<T: Copy + Default, const L: usize> Array<T, L> {
     /// assert_eq!(arr, array!(1, 2, 3));
     /// ```
     pub fn truncate(&mut self, len: usize) {
-        if len > self.len {
+        if len >= self.len {
             return;
         }
+        let old_len = self.len;
         self.len = len;
+        unsafe {
+            let tail = ptr::slice_from_raw_parts_mut(self.as_mut_ptr().add(len), old_len - len);
+            ptr::drop_in_place(tail);
+        }
     }
 
     pub fn as_slice(&self) -> &[T] {
-        &self.data[0..self.len]
+        unsafe { core::slice::from_raw_parts(self.as_ptr(), self.len) }
     }
 
     pub fn as_mut_slice(&mut self) -> &mut [T] {
-        &mut self.data[0..self.len]
-    }
-
-    pub fn as_vec(&self) -> Vec<T> {
-        self.as_slice().to_vec()
+        unsafe { core::slice::from_raw_parts_mut(self.as_mut_ptr(), self.len) }
     }
 
     /// Inserts an element at the specified index, shifting all elements after it to the right.
@@ -382,20 +686,66 @@ impl<T: Copy + Default, const L: usize> Array<T, L> {
         if self.len == L {
             panic!("len ({}) == capacity ({})", self.len, L);
         }
-        unsafe {
-            {
-                let p = self.data.as_mut_ptr().add(index);
-                if index < self.len {
-                    ptr::copy(p, p.add(1), self.len - index);
-                }
-                ptr::write(p, element);
-            }
-            self.len += 1;
+        unsafe { self.insert_unchecked(index, element) };
+    }
+
+    /// Inserts an element at the specified index, shifting all elements after it to the right, or
+    /// returns it in a [`CapacityError`] if the array is at full capacity.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is greater than the length of the array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::array;
+    /// let mut arr = array!(1, 2; 3);
+    /// assert!(arr.try_insert(0, 0).is_ok());
+    /// assert_eq!(arr.try_insert(0, 5).unwrap_err().element(), 5);
+    /// assert_eq!(arr, array!(0, 1, 2));
+    /// ```
+    pub fn try_insert(&mut self, index: usize, element: T) -> Result<(), CapacityError<T>> {
+        if index > self.len {
+            panic!("insert index ({}) > len ({})", index, self.len);
+        }
+        if self.len == L {
+            return Err(CapacityError::new(element, self.len, L));
         }
+        unsafe { self.insert_unchecked(index, element) };
+        Ok(())
+    }
+
+    /// Inserts an element into an array sorted by `f`, after any elements with an equal key, and
+    /// returns its index. Returns the element in a [`CapacityError`] if the array is full.
+    ///
+    /// The array must already be sorted by `f`, otherwise the position is unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::Array;
+    /// let mut arr = Array::<_, 3>::from(&[(1, 'a'), (3, 'c')]);
+    /// assert_eq!(arr.insert_sorted_by_key((2, 'b'), |&(k, _)| k), Ok(1));
+    /// assert_eq!(arr, [(1, 'a'), (2, 'b'), (3, 'c')]);
+    /// ```
+    pub fn insert_sorted_by_key<K: Ord>(
+        &mut self,
+        element: T,
+        mut f: impl FnMut(&T) -> K,
+    ) -> Result<usize, CapacityError<T>> {
+        let key = f(&element);
+        let index = self.as_slice().partition_point(|elt| f(elt) <= key);
+        self.try_insert(index, element).map(|()| index)
     }
 
+    /// Inserts an element at the specified index without checking the index or the capacity.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `index <= len` and that the array is not at full capacity.
     pub unsafe fn insert_unchecked(&mut self, index: usize, element: T) {
-        let p = self.data.as_mut_ptr().add(index);
+        let p = self.as_mut_ptr().add(index);
         if index < self.len {
             ptr::copy(p, p.add(1), self.len - index);
         }
@@ -422,89 +772,670 @@ impl<T: Copy + Default, const L: usize> Array<T, L> {
         if index >= len {
             panic!("remove index ({}) >= len ({})", index, len);
         }
-        unsafe {
-            let ret;
-            {
-                let ptr = self.as_mut_ptr().add(index);
-                ret = ptr::read(ptr);
-                ptr::copy(ptr.add(1), ptr, len - index - 1);
-            }
-            self.len -= 1;
-            ret
-        }
+        unsafe { self.remove_unchecked(index) }
     }
 
+    /// Removes and returns the element at the specified index without bounds checking.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `index < len`.
     pub unsafe fn remove_unchecked(&mut self, index: usize) -> T {
         let len = self.len;
-        let ret;
         let ptr = self.as_mut_ptr().add(index);
-        ret = ptr::read(ptr);
+        let ret = ptr::read(ptr);
         ptr::copy(ptr.add(1), ptr, len - index - 1);
         self.len -= 1;
         ret
     }
 
-    /// Clears the array, removing all elements.
+    /// Removes and returns the element at the specified index, replacing it with the last element.
+    ///
+    /// This does not preserve ordering, but is O(1).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the index is out of bounds.
     ///
     /// # Examples
     ///
     /// ```
     /// use arrayy::array;
-    /// let mut arr = array!(1, 2, 3);
-    /// arr.clear();
-    /// assert_eq!(arr.len(), 0);
+    /// let mut arr = array!(1, 2, 3, 4);
+    /// assert_eq!(arr.swap_remove(0), 1);
+    /// assert_eq!(arr, array!(4, 2, 3));
     /// ```
-    pub fn clear(&mut self) {
-        self.len = 0;
-    }
-
-    pub fn as_mut_ptr(&mut self) -> *mut T {
-        self.data.as_mut_ptr()
-    }
-
-    pub fn as_ptr(&self) -> *const T {
-        self.data.as_ptr()
-    }
-
-    pub fn as_mut_ptr_range(&mut self) -> Range<*mut T> {
-        self.data.as_mut_ptr_range()
-    }
-
-    pub fn as_ptr_range(&self) -> Range<*const T> {
-        self.data.as_ptr_range()
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        let len = self.len;
+        if index >= len {
+            panic!("swap_remove index ({}) >= len ({})", index, len);
+        }
+        unsafe {
+            let p = self.as_mut_ptr();
+            let ret = ptr::read(p.add(index));
+            ptr::copy(p.add(len - 1), p.add(index), 1);
+            self.len -= 1;
+            ret
+        }
     }
 
-    /// # Safety
+    /// Splits the array into two at the given index, returning the elements `[at, len)` in a new array.
     ///
-    /// The caller must ensure that the `Array` (`self`) outlives the `UnsafeIter`'s pointer (`*const T`) to the `self.data`'s buffer.
-    /// Failing to do so will result in undefined behavior.
+    /// # Panics
     ///
-    /// # Example of Undefined Behavior
+    /// Panics if `at` is greater than the length of the array.
+    ///
+    /// # Examples
     ///
-    /// ```rust,no_run
-    /// use arrayy::*;
-    /// fn x() -> UnsafeIter<u8> {
-    ///     let arr = Array::<u8, 10>::from(&[1, 2, 3]);
-    ///     unsafe { arr.into_iter() }
-    /// }
-    /// let mut iter = x();
-    /// println!("{}", iter.next().unwrap()); // can be anything
     /// ```
-    pub unsafe fn into_iter(&self) -> UnsafeIter<T> {
-        UnsafeIter {
-            data: self.data.as_ptr(),
-            idx: 0,
-            len: self.len,
+    /// use arrayy::array;
+    /// let mut arr = array!(1, 2, 3, 4);
+    /// let tail = arr.split_off(1);
+    /// assert_eq!(arr, array!(1));
+    /// assert_eq!(tail, array!(2, 3, 4));
+    /// ```
+    pub fn split_off(&mut self, at: usize) -> Self {
+        if at > self.len {
+            panic!("split_off index ({}) > len ({})", at, self.len);
         }
+        let mut other = Self::default();
+        let count = self.len - at;
+        unsafe {
+            ptr::copy_nonoverlapping(self.as_ptr().add(at), other.as_mut_ptr(), count);
+            other.len = count;
+        }
+        self.len = at;
+        other
     }
-}
 
-pub struct UnsafeIter<T> {
-    data: *const T,
-    idx: usize,
-    len: usize,
-}
+    /// Removes the elements in `range`, returning them as a double-ended iterator.
+    ///
+    /// The remaining elements are shifted into place when the iterator is dropped, even if it was
+    /// not fully consumed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than its end or if its end is greater than the
+    /// length of the array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::array;
+    /// let mut arr = array!(1, 2, 3, 4, 5);
+    /// let drained: Vec<i32> = arr.drain(1..3).collect();
+    /// assert_eq!(drained, vec![2, 3]);
+    /// assert_eq!(arr, array!(1, 4, 5));
+    /// ```
+    pub fn drain<R: RangeBounds<usize>>(&mut self, range: R) -> Drain<'_, T, L> {
+        let range = range_of(range, self.len);
+        Drain::new(self, range)
+    }
 
+    /// Replaces the elements in `range` with the elements of `replace_with`, returning the removed
+    /// elements in a new array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds or if the resulting length exceeds the capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::array;
+    /// let mut arr = array!(1, 2, 3; 5);
+    /// let removed = arr.splice(1..2, [7, 8, 9]);
+    /// assert_eq!(removed, array!(2));
+    /// assert_eq!(arr, array!(1, 7, 8, 9, 3));
+    /// ```
+    pub fn splice<R, I>(&mut self, range: R, replace_with: I) -> Self
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        match self.try_splice(range, replace_with) {
+            Ok(removed) => removed,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    /// Replaces the elements in `range` with the elements of `replace_with`, returning the removed
+    /// elements in a new array, or returns the replacement iterator in a [`CapacityError`] if the
+    /// resulting length would exceed the capacity. Nothing is modified on error.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::array;
+    /// let mut arr = array!(1, 2, 3; 4);
+    /// assert!(arr.try_splice(..1, [7, 8, 9]).is_err());
+    /// assert_eq!(arr.try_splice(..1, [7, 8]).unwrap(), array!(1));
+    /// assert_eq!(arr, array!(7, 8, 2, 3));
+    /// ```
+    pub fn try_splice<R, I>(
+        &mut self,
+        range: R,
+        replace_with: I,
+    ) -> Result<Self, CapacityError<I::IntoIter>>
+    where
+        R: RangeBounds<usize>,
+        I: IntoIterator<Item = T>,
+        I::IntoIter: ExactSizeIterator,
+    {
+        let range = range_of(range, self.len);
+        let iter = replace_with.into_iter();
+        if self.len - range.len() + iter.len() > L {
+            return Err(CapacityError::new(iter, self.len, L));
+        }
+        let start = range.start;
+        let removed = self.drain(range).collect();
+        let tail_len = self.len - start;
+        for elt in iter {
+            self.push(elt);
+        }
+        self.as_mut_slice()[start..].rotate_left(tail_len);
+        Ok(removed)
+    }
+
+    /// Creates an iterator that removes and yields the elements in `range` that match the predicate.
+    ///
+    /// Elements that do not match, or that are not visited because the iterator is dropped early,
+    /// are kept in their original order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::array;
+    /// let mut arr = array!(1, 2, 3, 4, 5, 6);
+    /// let evens: Vec<i32> = arr.extract_if(.., |x| *x % 2 == 0).collect();
+    /// assert_eq!(evens, vec![2, 4, 6]);
+    /// assert_eq!(arr, array!(1, 3, 5));
+    /// ```
+    pub fn extract_if<F, R>(&mut self, range: R, filter: F) -> ExtractIf<'_, T, F, L>
+    where
+        F: FnMut(&mut T) -> bool,
+        R: RangeBounds<usize>,
+    {
+        let range = range_of(range, self.len);
+        ExtractIf::new(self, range, filter)
+    }
+
+    /// Resizes the array so that `len` is equal to `new_len`, filling new slots with the results of `f`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_len` is greater than the capacity of the array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::array;
+    /// let mut arr = array!(1, 2; 4);
+    /// let mut n = 2;
+    /// arr.resize_with(4, || { n += 1; n });
+    /// assert_eq!(arr, array!(1, 2, 3, 4));
+    /// arr.resize_with(1, || unreachable!());
+    /// assert_eq!(arr, array!(1));
+    /// ```
+    pub fn resize_with(&mut self, new_len: usize, mut f: impl FnMut() -> T) {
+        if new_len > L {
+            panic!("new len ({}) > capacity ({})", new_len, L);
+        }
+        if new_len <= self.len {
+            self.truncate(new_len);
+            return;
+        }
+        while self.len < new_len {
+            unsafe { self.push_unchecked(f()) };
+        }
+    }
+
+    /// Clears the array, removing all elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::array;
+    /// let mut arr = array!(1, 2, 3);
+    /// arr.clear();
+    /// assert_eq!(arr.len(), 0);
+    /// ```
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    pub fn as_mut_ptr(&mut self) -> *mut T {
+        self.data.as_mut_ptr() as *mut T
+    }
+
+    pub fn as_ptr(&self) -> *const T {
+        self.data.as_ptr() as *const T
+    }
+
+    pub fn as_mut_ptr_range(&mut self) -> Range<*mut T> {
+        let start = self.as_mut_ptr();
+        start..unsafe { start.add(L) }
+    }
+
+    pub fn as_ptr_range(&self) -> Range<*const T> {
+        let start = self.as_ptr();
+        start..unsafe { start.add(L) }
+    }
+
+    /// Returns an iterator over copies of the elements through a raw pointer to the buffer.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the `Array` (`self`) outlives the `UnsafeIter`'s pointer (`*const T`) to the `self.data`'s buffer.
+    /// Failing to do so will result in undefined behavior.
+    ///
+    /// # Example of Undefined Behavior
+    ///
+    /// ```rust,no_run
+    /// # #![allow(deprecated)]
+    /// use arrayy::*;
+    /// fn x() -> UnsafeIter<u8> {
+    ///     let arr = Array::<u8, 10>::from(&[1, 2, 3]);
+    ///     unsafe { arr.unsafe_iter() }
+    /// }
+    /// let mut iter = x();
+    /// println!("{}", iter.next().unwrap()); // can be anything
+    /// ```
+    #[deprecated(note = "use `IntoIterator` (`arr.into_iter()` or `for x in arr`) instead")]
+    #[allow(deprecated)]
+    pub unsafe fn unsafe_iter(&self) -> UnsafeIter<T> {
+        UnsafeIter {
+            data: self.as_ptr(),
+            idx: 0,
+            len: self.len,
+        }
+    }
+
+    /// Moves the elements of another array to the end of this array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the combined length of both arrays exceeds the capacity of this array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::array;
+    /// let mut arr1 = array!(1, 2, 3; 5);
+    /// let arr2 = array!(4, 5);
+    /// arr1.append(arr2);
+    /// assert_eq!(arr1, array!(1, 2, 3, 4, 5));
+    /// ```
+    pub fn append<const M: usize>(&mut self, other: Array<T, M>) {
+        if other.len > L - self.len {
+            panic!(
+                "len ({}) + other len ({}) > capacity ({})",
+                self.len, other.len, L
+            );
+        }
+        unsafe { self.append_unchecked(other) };
+    }
+
+    /// Moves the elements of another array to the end of this array, or returns the other array
+    /// in a [`CapacityError`] if they do not fit. Nothing is appended on error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::array;
+    /// let mut arr1 = array!(1, 2, 3; 4);
+    /// let arr2 = array!(4, 5);
+    /// let err = arr1.try_append(arr2).unwrap_err();
+    /// assert_eq!((err.len(), err.capacity()), (3, 4));
+    /// assert_eq!(err.element(), array!(4, 5));
+    /// assert_eq!(arr1, array!(1, 2, 3));
+    /// ```
+    pub fn try_append<const M: usize>(
+        &mut self,
+        other: Array<T, M>,
+    ) -> Result<(), CapacityError<Array<T, M>>> {
+        if other.len > L - self.len {
+            return Err(CapacityError::new(other, self.len, L));
+        }
+        unsafe { self.append_unchecked(other) };
+        Ok(())
+    }
+
+    /// Moves the elements of another array to the end of this array without checking the
+    /// capacity.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the combined length of both arrays does not exceed the capacity of this array.
+    pub unsafe fn append_unchecked<const M: usize>(&mut self, mut other: Array<T, M>) {
+        let n = other.len;
+        // The elements now belong to `self`, so `other` must not drop them.
+        other.len = 0;
+        ptr::copy_nonoverlapping(other.data.as_ptr(), self.data.as_mut_ptr().add(self.len), n);
+        self.len += n;
+    }
+}
+
+impl<T: Clone, const L: usize> Array<T, L> {
+    /// Creates an array by cloning the elements of a slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the slice exceeds the capacity of the array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::Array;
+    /// let arr = Array::<u8, 10>::from(&[1, 2, 3]);
+    /// assert_eq!(arr.len(), 3);
+    /// ```
+    pub fn from(arr: &[T]) -> Self {
+        let mut s = Self::default();
+        s.append_slice(arr);
+        s
+    }
+
+    /// Creates an array by cloning the elements of a slice, or returns the slice in a
+    /// [`CapacityError`] if it does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::Array;
+    /// assert!(Array::<u8, 3>::try_from_slice(&[1, 2, 3]).is_ok());
+    /// let err = Array::<u8, 2>::try_from_slice(&[1, 2, 3]).unwrap_err();
+    /// assert_eq!(err.element(), &[1, 2, 3]);
+    /// ```
+    pub fn try_from_slice(arr: &[T]) -> Result<Self, CapacityError<&[T]>> {
+        let mut s = Self::default();
+        s.try_append_slice(arr)?;
+        Ok(s)
+    }
+
+    /// Appends the elements of a slice to the end of this array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the combined length exceeds the capacity of this array.
+    pub fn append_slice(&mut self, other: &[T]) {
+        if other.len() > L - self.len {
+            panic!(
+                "len ({}) + other len ({}) > capacity ({})",
+                self.len,
+                other.len(),
+                L
+            );
+        }
+        unsafe { self.append_slice_unchecked(other) };
+    }
+
+    /// Appends the elements of a slice to the end of this array, or returns the slice in a
+    /// [`CapacityError`] if it does not fit. Nothing is appended on error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::array;
+    /// let mut arr = array!(1,; 3);
+    /// assert!(arr.try_append_slice(&[2, 3]).is_ok());
+    /// assert_eq!(arr.try_append_slice(&[4]).unwrap_err().element(), &[4]);
+    /// ```
+    pub fn try_append_slice<'a>(&mut self, other: &'a [T]) -> Result<(), CapacityError<&'a [T]>> {
+        if other.len() > L - self.len {
+            return Err(CapacityError::new(other, self.len, L));
+        }
+        unsafe { self.append_slice_unchecked(other) };
+        Ok(())
+    }
+
+    /// Appends the elements of a slice without checking the capacity.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the combined length does not exceed the capacity of this array.
+    pub unsafe fn append_slice_unchecked(&mut self, other: &[T]) {
+        for elt in other {
+            self.push_unchecked(elt.clone());
+        }
+    }
+
+    /// Resizes the array so that `len` is equal to `new_len`, filling new slots with clones of `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_len` is greater than the capacity of the array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::array;
+    /// let mut arr = array!(1,; 4);
+    /// arr.resize(3, 0);
+    /// assert_eq!(arr, array!(1, 0, 0));
+    /// ```
+    pub fn resize(&mut self, new_len: usize, value: T) {
+        self.resize_with(new_len, || value.clone());
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn as_vec(&self) -> Vec<T> {
+        self.as_slice().to_vec()
+    }
+}
+
+impl<T, const L: usize> Array<T, L> {
+    /// Concatenates two arrays into a new array of capacity `N`.
+    ///
+    /// `L + M <= N` is checked at compile time, so this never fails at runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::{array, Array};
+    /// let arr: Array<i32, 5> = array!(1, 2; 3).concat(array!(3, 4));
+    /// assert_eq!(arr, array!(1, 2, 3, 4));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use arrayy::{array, Array};
+    /// let arr: Array<i32, 4> = array!(1, 2; 3).concat(array!(3, 4));
+    /// ```
+    pub fn concat<const M: usize, const N: usize>(self, other: Array<T, M>) -> Array<T, N> {
+        let () = CapacityCheck::<L, M, N>::SUM_FITS;
+        let mut arr = Array::default();
+        for elt in self.into_iter().chain(other) {
+            unsafe { arr.push_unchecked(elt) };
+        }
+        arr
+    }
+
+    /// Splits the array at index `N` into an array of capacity `N` holding the first `N`
+    /// elements and an array of capacity `R` holding the rest.
+    ///
+    /// `N <= L <= N + R` is checked at compile time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is greater than the length of the array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::{array, Array};
+    /// let (head, tail): (Array<i32, 2>, Array<i32, 3>) = array!(1, 2, 3, 4).split_at_const();
+    /// assert_eq!(head, array!(1, 2));
+    /// assert_eq!(tail, array!(3, 4));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use arrayy::{array, Array};
+    /// let (head, tail): (Array<i32, 2>, Array<i32, 1>) = array!(1, 2, 3, 4).split_at_const();
+    /// ```
+    pub fn split_at_const<const N: usize, const R: usize>(self) -> (Array<T, N>, Array<T, R>) {
+        let () = CapacityCheck::<N, R, L>::SPLIT_FITS;
+        if N > self.len {
+            panic!("split index ({}) > len ({})", N, self.len);
+        }
+        let mut head = Array::default();
+        let mut tail = Array::default();
+        for (i, elt) in self.into_iter().enumerate() {
+            unsafe {
+                if i < N {
+                    head.push_unchecked(elt);
+                } else {
+                    tail.push_unchecked(elt);
+                }
+            }
+        }
+        (head, tail)
+    }
+
+    /// Moves the elements into an array of capacity `N`.
+    ///
+    /// `L <= N` is checked at compile time, so this never fails at runtime. Use
+    /// [`Array::try_resize_capacity`] to shrink the capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::{array, Array};
+    /// let arr: Array<i32, 8> = array!(1, 2, 3).resize_capacity();
+    /// assert_eq!(arr.capacity(), 8);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use arrayy::{array, Array};
+    /// let arr: Array<i32, 2> = array!(1, 2, 3).resize_capacity();
+    /// ```
+    pub fn resize_capacity<const N: usize>(self) -> Array<T, N> {
+        let () = CapacityCheck::<L, 0, N>::SUM_FITS;
+        let mut arr = Array::default();
+        for elt in self {
+            unsafe { arr.push_unchecked(elt) };
+        }
+        arr
+    }
+
+    /// Moves the elements into an array of capacity `N`, or returns the array in a
+    /// [`CapacityError`] if its length exceeds `N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::{array, Array};
+    /// let arr: Array<i32, 2> = array!(1, 2; 8).try_resize_capacity().unwrap();
+    /// assert_eq!(arr, array!(1, 2));
+    /// assert!(array!(1, 2, 3).try_resize_capacity::<2>().is_err());
+    /// ```
+    pub fn try_resize_capacity<const N: usize>(self) -> Result<Array<T, N>, CapacityError<Self>> {
+        if self.len > N {
+            let len = self.len;
+            return Err(CapacityError::new(self, len, N));
+        }
+        let mut arr = Array::default();
+        for elt in self {
+            unsafe { arr.push_unchecked(elt) };
+        }
+        Ok(arr)
+    }
+}
+
+impl<T, const N: usize, const L: usize> From<[T; N]> for Array<T, L> {
+    /// Moves the elements of `[T; N]` into an array of capacity `L`.
+    ///
+    /// `N <= L` is checked at compile time. Note that `Array::from` resolves to the inherent
+    /// slice constructor; use `.into()` or `From::from` to call this.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::{array, Array};
+    /// let arr: Array<i32, 5> = [1, 2, 3].into();
+    /// assert_eq!(arr, array!(1, 2, 3));
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use arrayy::Array;
+    /// let arr: Array<i32, 2> = [1, 2, 3].into();
+    /// ```
+    fn from(data: [T; N]) -> Self {
+        let () = CapacityCheck::<N, 0, L>::SUM_FITS;
+        let mut arr = Array::default();
+        for elt in data {
+            unsafe { arr.push_unchecked(elt) };
+        }
+        arr
+    }
+}
+
+impl<T: PartialEq, const L: usize> Array<T, L> {
+    /// Removes consecutive repeated elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::array;
+    /// let mut arr = array!(1, 1, 2, 3, 3, 3, 1);
+    /// arr.dedup();
+    /// assert_eq!(arr, array!(1, 2, 3, 1));
+    /// ```
+    pub fn dedup(&mut self) {
+        self.dedup_by(|a, b| a == b);
+    }
+}
+
+impl<T: Ord, const L: usize> Array<T, L> {
+    /// Inserts an element into a sorted array, after any equal elements, and returns its index.
+    /// Returns the element in a [`CapacityError`] if the array is full.
+    ///
+    /// The array must already be sorted, otherwise the position is unspecified. See
+    /// [`SortedArray`] for a wrapper that keeps the array sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::array;
+    /// let mut arr = array!(1, 3, 5; 4);
+    /// assert_eq!(arr.insert_sorted(4), Ok(2));
+    /// assert_eq!(arr, array!(1, 3, 4, 5));
+    /// assert_eq!(arr.insert_sorted(0).unwrap_err().element(), 0);
+    /// ```
+    pub fn insert_sorted(&mut self, element: T) -> Result<usize, CapacityError<T>> {
+        let index = self.as_slice().partition_point(|elt| *elt <= element);
+        self.try_insert(index, element).map(|()| index)
+    }
+}
+
+impl<T, const L: usize> Drop for Array<T, L> {
+    fn drop(&mut self) {
+        unsafe { ptr::drop_in_place(self.as_mut_slice()) };
+    }
+}
+
+impl<T: Clone, const L: usize> Clone for Array<T, L> {
+    fn clone(&self) -> Self {
+        Self::from(self.as_slice())
+    }
+}
+
+#[deprecated(note = "use `IntoIter` instead")]
+pub struct UnsafeIter<T> {
+    data: *const T,
+    idx: usize,
+    len: usize,
+}
+
+#[allow(deprecated)]
 impl<T: Copy> Iterator for UnsafeIter<T> {
     type Item = T;
 
@@ -519,29 +1450,127 @@ impl<T: Copy> Iterator for UnsafeIter<T> {
     }
 }
 
-impl<T: Debug + Copy + Default, const L: usize> Debug for Array<T, L> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+#[cfg(feature = "alloc")]
+impl<T, const L: usize> From<Array<T, L>> for Vec<T> {
+    /// Moves the elements of the array into a new `Vec`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::array;
+    /// let v: Vec<i32> = array!(1, 2, 3; 5).into();
+    /// assert_eq!(v, vec![1, 2, 3]);
+    /// ```
+    fn from(arr: Array<T, L>) -> Self {
+        let mut v = Vec::with_capacity(arr.len());
+        v.extend(arr);
+        v
+    }
+}
+
+impl<T: Debug, const L: usize> Debug for Array<T, L> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{:?}", self.as_slice())
     }
 }
 
-impl<T: Copy + Default + PartialEq, const L: usize> Eq for Array<T, L> {}
+impl<T: Eq, const L: usize> Eq for Array<T, L> {}
 
-impl<T: Copy + Default + PartialEq, const L: usize, const X: usize> PartialEq<Array<T, X>>
-    for Array<T, L>
-{
+impl<T: PartialEq, const L: usize, const X: usize> PartialEq<Array<T, X>> for Array<T, L> {
     fn eq(&self, other: &Array<T, X>) -> bool {
         self.len == other.len && self.as_slice() == other.as_slice()
     }
 }
 
+impl<T: PartialEq, const L: usize> PartialEq<[T]> for Array<T, L> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: PartialEq, const L: usize> PartialEq<&[T]> for Array<T, L> {
+    fn eq(&self, other: &&[T]) -> bool {
+        self.as_slice() == *other
+    }
+}
+
+impl<T: PartialEq, const L: usize, const N: usize> PartialEq<[T; N]> for Array<T, L> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: PartialEq, const L: usize> PartialEq<Vec<T>> for Array<T, L> {
+    fn eq(&self, other: &Vec<T>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialEq, const L: usize> PartialEq<Array<T, L>> for [T] {
+    fn eq(&self, other: &Array<T, L>) -> bool {
+        self == other.as_slice()
+    }
+}
+
+impl<T: PartialEq, const L: usize> PartialEq<Array<T, L>> for &[T] {
+    fn eq(&self, other: &Array<T, L>) -> bool {
+        *self == other.as_slice()
+    }
+}
+
+impl<T: PartialEq, const L: usize, const N: usize> PartialEq<Array<T, L>> for [T; N] {
+    fn eq(&self, other: &Array<T, L>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: PartialEq, const L: usize> PartialEq<Array<T, L>> for Vec<T> {
+    fn eq(&self, other: &Array<T, L>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: PartialOrd, const L: usize, const X: usize> PartialOrd<Array<T, X>> for Array<T, L> {
+    fn partial_cmp(&self, other: &Array<T, X>) -> Option<Ordering> {
+        self.as_slice().partial_cmp(other.as_slice())
+    }
+}
+
+impl<T: Ord, const L: usize> Ord for Array<T, L> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.as_slice().cmp(other.as_slice())
+    }
+}
+
+impl<T: Hash, const L: usize> Hash for Array<T, L> {
+    /// Hashes the live elements the same way as `[T]`, so that lookups by `&[T]` through
+    /// [`Borrow`] are consistent.
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state)
+    }
+}
+
+impl<T, const L: usize> Borrow<[T]> for Array<T, L> {
+    fn borrow(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const L: usize> BorrowMut<[T]> for Array<T, L> {
+    fn borrow_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
 impl<T, const L: usize> AsRef<Array<T, L>> for Array<T, L> {
     fn as_ref(&self) -> &Array<T, L> {
         self
     }
 }
 
-impl<T: Copy + Default, const L: usize> AsRef<[T]> for Array<T, L> {
+impl<T, const L: usize> AsRef<[T]> for Array<T, L> {
     fn as_ref(&self) -> &[T] {
         self.as_slice()
     }
@@ -553,13 +1582,13 @@ impl<T, const L: usize> AsMut<Array<T, L>> for Array<T, L> {
     }
 }
 
-impl<T: Copy + Default, const L: usize> AsMut<[T]> for Array<T, L> {
+impl<T, const L: usize> AsMut<[T]> for Array<T, L> {
     fn as_mut(&mut self) -> &mut [T] {
         self.as_mut_slice()
     }
 }
 
-impl<T: Copy + Default, const L: usize> Deref for Array<T, L> {
+impl<T, const L: usize> Deref for Array<T, L> {
     type Target = [T];
 
     fn deref(&self) -> &Self::Target {
@@ -567,7 +1596,7 @@ impl<T: Copy + Default, const L: usize> Deref for Array<T, L> {
     }
 }
 
-impl<T: Copy + Default, const L: usize> DerefMut for Array<T, L> {
+impl<T, const L: usize> DerefMut for Array<T, L> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         self.as_mut_slice()
     }
@@ -594,15 +1623,19 @@ macro_rules! array {
     ($e:expr ;$cap:expr) => {
         $crate::Array::<_, $cap>::new([$e; $cap])
     };
-    ($e:expr, ;$cap:expr) => {
-        $crate::Array::<_, $cap>::from(&[$e])
-    };
+    ($e:expr, ;$cap:expr) => {{
+        let mut arr = $crate::Array::<_, $cap>::default();
+        arr.push($e);
+        arr
+    }};
     (;$cap:expr) => {
         $crate::Array::<_, $cap>::default()
     };
-    ($($es:expr),+; $cap:expr) => {
-        $crate::Array::<_, $cap>::from(&[$($es),+])
-    };
+    ($($es:expr),+; $cap:expr) => {{
+        let mut arr = $crate::Array::<_, $cap>::default();
+        $(arr.push($es);)+
+        arr
+    }};
     ($($es:expr),+) => {
         $crate::Array::<_, { $crate::count!($($es),+) }>::new([$($es),+])
     };
@@ -0,0 +1,388 @@
+//! Fixed-capacity least-recently-used cache backed by an [`Array`].
+
+use core::{
+    borrow::Borrow,
+    fmt::{self, Debug},
+    iter::FusedIterator,
+    mem,
+};
+
+use crate::Array;
+
+const NIL: usize = usize::MAX;
+
+#[derive(Clone)]
+struct Node<K, V> {
+    key: K,
+    value: V,
+    /// More recently used neighbor.
+    prev: usize,
+    /// Less recently used neighbor.
+    next: usize,
+}
+
+/// Fixed-capacity cache storing up to `L` entries inline, evicting the least recently used entry
+/// when full.
+///
+/// Entries live densely in an [`Array`] and are linked in recency order by index, so promotion and
+/// eviction never move other entries. Lookups compare keys linearly, which is fast for the small
+/// capacities the cache is meant for.
+///
+/// # Examples
+///
+/// ```
+/// use arrayy::{lru::Put, ArrayLru};
+///
+/// let mut cache = ArrayLru::<&str, u32, 2>::new();
+/// cache.put("a", 1);
+/// cache.put("b", 2);
+/// assert_eq!(cache.get("a"), Some(&1)); // "a" is now the most recent
+/// assert_eq!(cache.put("a", 10), Put::Replaced(1));
+/// assert_eq!(cache.put("c", 3), Put::Evicted("b", 2));
+/// assert_eq!(cache.get("b"), None);
+/// assert_eq!((cache.hits(), cache.misses()), (1, 1));
+/// ```
+pub struct ArrayLru<K, V, const L: usize> {
+    nodes: Array<Node<K, V>, L>,
+    /// Most recently used entry.
+    head: usize,
+    /// Least recently used entry.
+    tail: usize,
+    hits: u64,
+    misses: u64,
+}
+
+impl<K, V, const L: usize> Default for ArrayLru<K, V, L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V, const L: usize> ArrayLru<K, V, L> {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Self {
+            nodes: Array::default(),
+            head: NIL,
+            tail: NIL,
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    pub const fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    pub const fn capacity(&self) -> usize {
+        L
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub const fn is_full(&self) -> bool {
+        self.nodes.len() == L
+    }
+
+    /// Removes all entries. The hit and miss counters are kept.
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+        self.head = NIL;
+        self.tail = NIL;
+    }
+
+    /// Returns the number of [`get`](Self::get) and [`get_mut`](Self::get_mut) calls that found
+    /// their key.
+    pub const fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Returns the number of [`get`](Self::get) and [`get_mut`](Self::get_mut) calls that did not
+    /// find their key.
+    pub const fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    /// Resets the hit and miss counters to zero.
+    pub fn reset_stats(&mut self) {
+        self.hits = 0;
+        self.misses = 0;
+    }
+
+    /// Returns the least recently used entry without promoting it.
+    pub fn peek_lru(&self) -> Option<(&K, &V)> {
+        let node = self.nodes.get(self.tail)?;
+        Some((&node.key, &node.value))
+    }
+
+    /// Removes and returns the least recently used entry.
+    pub fn pop_lru(&mut self) -> Option<(K, V)> {
+        if self.tail == NIL {
+            return None;
+        }
+        let node = self.unlink_remove(self.tail);
+        Some((node.key, node.value))
+    }
+
+    /// Returns an iterator over the entries from most to least recently used.
+    pub fn iter(&self) -> Iter<'_, K, V, L> {
+        Iter {
+            lru: self,
+            front: self.head,
+            back: self.tail,
+            len: self.len(),
+        }
+    }
+
+    fn unlink(&mut self, index: usize) {
+        let (prev, next) = (self.nodes[index].prev, self.nodes[index].next);
+        match prev {
+            NIL => self.head = next,
+            prev => self.nodes[prev].next = next,
+        }
+        match next {
+            NIL => self.tail = prev,
+            next => self.nodes[next].prev = prev,
+        }
+    }
+
+    fn link_front(&mut self, index: usize) {
+        self.nodes[index].prev = NIL;
+        self.nodes[index].next = self.head;
+        match self.head {
+            NIL => self.tail = index,
+            head => self.nodes[head].prev = index,
+        }
+        self.head = index;
+    }
+
+    fn promote(&mut self, index: usize) {
+        if self.head != index {
+            self.unlink(index);
+            self.link_front(index);
+        }
+    }
+
+    /// Unlinks and swap-removes the node at `index`, relinking the node moved into its place.
+    fn unlink_remove(&mut self, index: usize) -> Node<K, V> {
+        self.unlink(index);
+        let moved = self.nodes.len() - 1;
+        let node = self.nodes.swap_remove(index);
+        if moved != index {
+            let (prev, next) = (self.nodes[index].prev, self.nodes[index].next);
+            match prev {
+                NIL => self.head = index,
+                prev => self.nodes[prev].next = index,
+            }
+            match next {
+                NIL => self.tail = index,
+                next => self.nodes[next].prev = index,
+            }
+        }
+        node
+    }
+}
+
+impl<K: Eq, V, const L: usize> ArrayLru<K, V, L> {
+    fn position<Q>(&self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.nodes
+            .as_slice()
+            .iter()
+            .position(|node| node.key.borrow() == key)
+    }
+
+    fn lookup<Q>(&mut self, key: &Q) -> Option<usize>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        match self.position(key) {
+            Some(index) => {
+                self.hits += 1;
+                self.promote(index);
+                Some(index)
+            }
+            None => {
+                self.misses += 1;
+                None
+            }
+        }
+    }
+
+    /// Returns the value for `key` and marks it as the most recently used entry.
+    pub fn get<Q>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let index = self.lookup(key)?;
+        Some(&self.nodes[index].value)
+    }
+
+    /// Returns the value for `key` mutably and marks it as the most recently used entry.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let index = self.lookup(key)?;
+        Some(&mut self.nodes[index].value)
+    }
+
+    /// Returns the value for `key` without changing its recency or the counters.
+    pub fn peek<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let index = self.position(key)?;
+        Some(&self.nodes[index].value)
+    }
+
+    /// Returns `true` if the cache contains `key`, without changing its recency.
+    pub fn contains<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        self.position(key).is_some()
+    }
+
+    /// Inserts an entry as the most recently used one.
+    ///
+    /// If the key was already present, its value is replaced and the old value is returned in
+    /// [`Put::Replaced`]. Otherwise, if the cache is full, the least recently used entry is
+    /// evicted and returned in [`Put::Evicted`]. With a capacity of zero the new pair is handed
+    /// back in [`Put::Rejected`].
+    pub fn put(&mut self, key: K, value: V) -> Put<K, V> {
+        if let Some(index) = self.position(&key) {
+            self.promote(index);
+            return Put::Replaced(mem::replace(&mut self.nodes[index].value, value));
+        }
+        if L == 0 {
+            return Put::Rejected(key, value);
+        }
+        let evicted = if self.is_full() { self.pop_lru() } else { None };
+        let index = self.nodes.len();
+        unsafe {
+            self.nodes.push_unchecked(Node {
+                key,
+                value,
+                prev: NIL,
+                next: NIL,
+            })
+        };
+        self.link_front(index);
+        match evicted {
+            Some((key, value)) => Put::Evicted(key, value),
+            None => Put::Inserted,
+        }
+    }
+
+    /// Removes the entry for `key` and returns its value.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
+        let index = self.position(key)?;
+        Some(self.unlink_remove(index).value)
+    }
+}
+
+/// The outcome of [`ArrayLru::put`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Put<K, V> {
+    /// The key was new and the cache had room for it.
+    Inserted,
+    /// The key was already present; holds its previous value.
+    Replaced(V),
+    /// The key was new and the cache was full; holds the least recently used entry, which was
+    /// removed to make room.
+    Evicted(K, V),
+    /// The cache has a capacity of zero; holds the pair that could not be stored.
+    Rejected(K, V),
+}
+
+impl<K: Clone, V: Clone, const L: usize> Clone for ArrayLru<K, V, L> {
+    fn clone(&self) -> Self {
+        Self {
+            nodes: self.nodes.clone(),
+            head: self.head,
+            tail: self.tail,
+            hits: self.hits,
+            misses: self.misses,
+        }
+    }
+}
+
+impl<K: Debug, V: Debug, const L: usize> Debug for ArrayLru<K, V, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<'a, K, V, const L: usize> IntoIterator for &'a ArrayLru<K, V, L> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V, L>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the entries of an [`ArrayLru`], from most to least recently used.
+///
+/// Created by [`ArrayLru::iter`].
+pub struct Iter<'a, K, V, const L: usize> {
+    lru: &'a ArrayLru<K, V, L>,
+    front: usize,
+    back: usize,
+    len: usize,
+}
+
+impl<'a, K, V, const L: usize> Iterator for Iter<'a, K, V, L> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let node = &self.lru.nodes[self.front];
+        self.front = node.next;
+        self.len -= 1;
+        Some((&node.key, &node.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.len, Some(self.len))
+    }
+}
+
+impl<K, V, const L: usize> DoubleEndedIterator for Iter<'_, K, V, L> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.len == 0 {
+            return None;
+        }
+        let node = &self.lru.nodes[self.back];
+        self.back = node.prev;
+        self.len -= 1;
+        Some((&node.key, &node.value))
+    }
+}
+
+impl<K, V, const L: usize> ExactSizeIterator for Iter<'_, K, V, L> {}
+
+impl<K, V, const L: usize> FusedIterator for Iter<'_, K, V, L> {}
+
+impl<K, V, const L: usize> Clone for Iter<'_, K, V, L> {
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
@@ -0,0 +1,565 @@
+//! Fixed-capacity sorted map backed by an [`Array`] of key-value pairs.
+
+use core::{
+    borrow::Borrow,
+    fmt::{self, Debug},
+    iter::FusedIterator,
+    mem,
+    ops::{Bound, Index, RangeBounds},
+    slice,
+};
+
+use crate::{Array, CapacityError};
+
+/// Fixed-capacity map storing up to `L` key-value pairs inline, sorted by key.
+///
+/// Lookups are O(log n) binary searches; insertions and removals shift the following entries like
+/// [`Array::insert`] and [`Array::remove`].
+///
+/// # Examples
+///
+/// ```
+/// use arrayy::ArrayMap;
+/// let mut map = ArrayMap::<&str, i32, 4>::new();
+/// assert_eq!(map.insert("b", 2), Ok(None));
+/// assert_eq!(map.insert("a", 1), Ok(None));
+/// assert_eq!(map.insert("b", 3), Ok(Some(2)));
+/// assert_eq!(map.get("b"), Some(&3));
+/// assert_eq!(map.keys().copied().collect::<Vec<_>>(), vec!["a", "b"]);
+/// ```
+pub struct ArrayMap<K, V, const L: usize> {
+    entries: Array<(K, V), L>,
+}
+
+impl<K, V, const L: usize> Default for ArrayMap<K, V, L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V, const L: usize> ArrayMap<K, V, L> {
+    /// Creates an empty map.
+    pub fn new() -> Self {
+        Self {
+            entries: Array::default(),
+        }
+    }
+
+    pub const fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub const fn capacity(&self) -> usize {
+        L
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Clears the map, removing all entries.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Returns the entries as a slice of key-value pairs, sorted by key.
+    pub fn as_slice(&self) -> &[(K, V)] {
+        self.entries.as_slice()
+    }
+
+    /// Returns an iterator over the entries, sorted by key.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            inner: self.entries.as_slice().iter(),
+        }
+    }
+
+    /// Returns an iterator over the entries with mutable references to the values, sorted by key.
+    pub fn iter_mut(&mut self) -> IterMut<'_, K, V> {
+        IterMut {
+            inner: self.entries.as_mut_slice().iter_mut(),
+        }
+    }
+
+    /// Returns an iterator over the keys, in sorted order.
+    pub fn keys(&self) -> impl DoubleEndedIterator<Item = &K> + ExactSizeIterator {
+        self.entries.as_slice().iter().map(|(k, _)| k)
+    }
+
+    /// Returns an iterator over the values, sorted by key.
+    pub fn values(&self) -> impl DoubleEndedIterator<Item = &V> + ExactSizeIterator {
+        self.entries.as_slice().iter().map(|(_, v)| v)
+    }
+
+    /// Returns an iterator over mutable references to the values, sorted by key.
+    pub fn values_mut(&mut self) -> impl DoubleEndedIterator<Item = &mut V> + ExactSizeIterator {
+        self.entries.as_mut_slice().iter_mut().map(|(_, v)| v)
+    }
+
+    pub fn first_key_value(&self) -> Option<(&K, &V)> {
+        self.entries.first().map(|(k, v)| (k, v))
+    }
+
+    pub fn last_key_value(&self) -> Option<(&K, &V)> {
+        self.entries.last().map(|(k, v)| (k, v))
+    }
+
+    /// Removes and returns the entry with the smallest key.
+    pub fn pop_first(&mut self) -> Option<(K, V)> {
+        if self.entries.is_empty() {
+            None
+        } else {
+            Some(self.entries.remove(0))
+        }
+    }
+
+    /// Removes and returns the entry with the largest key.
+    pub fn pop_last(&mut self) -> Option<(K, V)> {
+        self.entries.pop()
+    }
+
+    /// Retains only the entries that match the predicate.
+    pub fn retain(&mut self, mut f: impl FnMut(&K, &mut V) -> bool) {
+        self.entries.retain_mut(|(k, v)| f(k, v));
+    }
+}
+
+impl<K: Ord, V, const L: usize> ArrayMap<K, V, L> {
+    fn search<Q>(&self, key: &Q) -> Result<usize, usize>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.entries.binary_search_by(|(k, _)| k.borrow().cmp(key))
+    }
+
+    /// Returns a reference to the value for `key`, or `None` if it is not present.
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.get_key_value(key).map(|(_, v)| v)
+    }
+
+    /// Returns references to the stored key and value for `key`, or `None` if it is not present.
+    pub fn get_key_value<Q>(&self, key: &Q) -> Option<(&K, &V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let i = self.search(key).ok()?;
+        let (k, v) = unsafe { self.entries.get_unchecked(i) };
+        Some((k, v))
+    }
+
+    /// Returns a mutable reference to the value for `key`, or `None` if it is not present.
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let i = self.search(key).ok()?;
+        Some(unsafe { &mut self.entries.get_unchecked_mut(i).1 })
+    }
+
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.search(key).is_ok()
+    }
+
+    /// Inserts a key-value pair, returning the displaced value if the key was already present, or
+    /// returns the pair in a [`CapacityError`] if the key is new and the map is full.
+    ///
+    /// The stored key is not updated when the key is already present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::ArrayMap;
+    /// let mut map = ArrayMap::<i32, &str, 1>::new();
+    /// assert_eq!(map.insert(1, "a"), Ok(None));
+    /// assert_eq!(map.insert(1, "b"), Ok(Some("a")));
+    /// assert_eq!(map.insert(2, "c").unwrap_err().element(), (2, "c"));
+    /// ```
+    pub fn insert(&mut self, key: K, value: V) -> Result<Option<V>, CapacityError<(K, V)>> {
+        match self.search(&key) {
+            Ok(i) => {
+                let slot = unsafe { &mut self.entries.get_unchecked_mut(i).1 };
+                Ok(Some(mem::replace(slot, value)))
+            }
+            Err(i) => self.entries.try_insert(i, (key, value)).map(|()| None),
+        }
+    }
+
+    /// Removes `key` from the map, returning its value, or `None` if it was not present.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.remove_entry(key).map(|(_, v)| v)
+    }
+
+    /// Removes `key` from the map, returning the stored key and value, or `None` if it was not
+    /// present.
+    pub fn remove_entry<Q>(&mut self, key: &Q) -> Option<(K, V)>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let i = self.search(key).ok()?;
+        Some(self.entries.remove(i))
+    }
+
+    /// Returns the entry for `key` for in-place manipulation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::ArrayMap;
+    /// let mut counts = ArrayMap::<char, u32, 8>::new();
+    /// for c in "hello".chars() {
+    ///     *counts.entry(c).or_insert(0) += 1;
+    /// }
+    /// assert_eq!(counts.get(&'l'), Some(&2));
+    /// ```
+    pub fn entry(&mut self, key: K) -> Entry<'_, K, V, L> {
+        match self.search(&key) {
+            Ok(index) => Entry::Occupied(OccupiedEntry { map: self, index }),
+            Err(index) => Entry::Vacant(VacantEntry {
+                map: self,
+                key,
+                index,
+            }),
+        }
+    }
+
+    /// Returns an iterator over the entries whose keys lie in `range`, sorted by key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than its end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::ArrayMap;
+    /// let map: ArrayMap<i32, char, 8> = [(1, 'a'), (3, 'c'), (5, 'e'), (7, 'g')].into_iter().collect();
+    /// let keys: Vec<i32> = map.range(2..=5).map(|(k, _)| *k).collect();
+    /// assert_eq!(keys, vec![3, 5]);
+    /// ```
+    pub fn range<Q, R>(&self, range: R) -> Iter<'_, K, V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        let entries = self.entries.as_slice();
+        let start = match range.start_bound() {
+            Bound::Included(q) => entries.partition_point(|(k, _)| k.borrow() < q),
+            Bound::Excluded(q) => entries.partition_point(|(k, _)| k.borrow() <= q),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(q) => entries.partition_point(|(k, _)| k.borrow() <= q),
+            Bound::Excluded(q) => entries.partition_point(|(k, _)| k.borrow() < q),
+            Bound::Unbounded => entries.len(),
+        };
+        if start > end {
+            panic!("range start ({}) > end ({})", start, end);
+        }
+        Iter {
+            inner: entries[start..end].iter(),
+        }
+    }
+}
+
+/// A view into a single entry of an [`ArrayMap`], which may be vacant or occupied.
+///
+/// Created by [`ArrayMap::entry`].
+pub enum Entry<'a, K, V, const L: usize> {
+    Occupied(OccupiedEntry<'a, K, V, L>),
+    Vacant(VacantEntry<'a, K, V, L>),
+}
+
+/// An occupied entry of an [`ArrayMap`].
+pub struct OccupiedEntry<'a, K, V, const L: usize> {
+    map: &'a mut ArrayMap<K, V, L>,
+    index: usize,
+}
+
+/// A vacant entry of an [`ArrayMap`].
+pub struct VacantEntry<'a, K, V, const L: usize> {
+    map: &'a mut ArrayMap<K, V, L>,
+    key: K,
+    index: usize,
+}
+
+impl<'a, K, V, const L: usize> Entry<'a, K, V, L> {
+    pub fn key(&self) -> &K {
+        match self {
+            Entry::Occupied(e) => e.key(),
+            Entry::Vacant(e) => e.key(),
+        }
+    }
+
+    /// Inserts `default` if the entry is vacant, and returns a mutable reference to the value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the entry is vacant and the map is full.
+    pub fn or_insert(self, default: V) -> &'a mut V {
+        self.or_insert_with(|| default)
+    }
+
+    /// Inserts the result of `default` if the entry is vacant, and returns a mutable reference to
+    /// the value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the entry is vacant and the map is full.
+    pub fn or_insert_with(self, default: impl FnOnce() -> V) -> &'a mut V {
+        match self {
+            Entry::Occupied(e) => e.into_mut(),
+            Entry::Vacant(e) => e.insert(default()),
+        }
+    }
+
+    /// Calls `f` on the value if the entry is occupied.
+    pub fn and_modify(mut self, f: impl FnOnce(&mut V)) -> Self {
+        if let Entry::Occupied(e) = &mut self {
+            f(e.get_mut());
+        }
+        self
+    }
+}
+
+impl<'a, K, V: Default, const L: usize> Entry<'a, K, V, L> {
+    /// Inserts `V::default()` if the entry is vacant, and returns a mutable reference to the value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the entry is vacant and the map is full.
+    pub fn or_default(self) -> &'a mut V {
+        self.or_insert_with(V::default)
+    }
+}
+
+impl<'a, K, V, const L: usize> OccupiedEntry<'a, K, V, L> {
+    pub fn key(&self) -> &K {
+        unsafe { &self.map.entries.get_unchecked(self.index).0 }
+    }
+
+    pub fn get(&self) -> &V {
+        unsafe { &self.map.entries.get_unchecked(self.index).1 }
+    }
+
+    pub fn get_mut(&mut self) -> &mut V {
+        unsafe { &mut self.map.entries.get_unchecked_mut(self.index).1 }
+    }
+
+    /// Converts the entry into a mutable reference to the value with the lifetime of the map.
+    pub fn into_mut(self) -> &'a mut V {
+        unsafe { &mut self.map.entries.get_unchecked_mut(self.index).1 }
+    }
+
+    /// Replaces the value, returning the old one.
+    pub fn insert(&mut self, value: V) -> V {
+        mem::replace(self.get_mut(), value)
+    }
+
+    /// Removes the entry from the map, returning the value.
+    pub fn remove(self) -> V {
+        self.remove_entry().1
+    }
+
+    /// Removes the entry from the map, returning the key and value.
+    pub fn remove_entry(self) -> (K, V) {
+        self.map.entries.remove(self.index)
+    }
+}
+
+impl<'a, K, V, const L: usize> VacantEntry<'a, K, V, L> {
+    pub fn key(&self) -> &K {
+        &self.key
+    }
+
+    pub fn into_key(self) -> K {
+        self.key
+    }
+
+    /// Inserts the value, returning a mutable reference to it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the map is full.
+    pub fn insert(self, value: V) -> &'a mut V {
+        match self.try_insert(value) {
+            Ok(v) => v,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    /// Inserts the value, returning a mutable reference to it, or returns the key-value pair in a
+    /// [`CapacityError`] if the map is full.
+    pub fn try_insert(self, value: V) -> Result<&'a mut V, CapacityError<(K, V)>> {
+        self.map.entries.try_insert(self.index, (self.key, value))?;
+        Ok(unsafe { &mut self.map.entries.get_unchecked_mut(self.index).1 })
+    }
+}
+
+/// An iterator over the entries of an [`ArrayMap`], sorted by key.
+#[derive(Clone)]
+pub struct Iter<'a, K, V> {
+    inner: slice::Iter<'a, (K, V)>,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(k, v)| (k, v))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for Iter<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(k, v)| (k, v))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
+
+impl<K, V> FusedIterator for Iter<'_, K, V> {}
+
+/// A mutable iterator over the entries of an [`ArrayMap`], sorted by key.
+pub struct IterMut<'a, K, V> {
+    inner: slice::IterMut<'a, (K, V)>,
+}
+
+impl<'a, K, V> Iterator for IterMut<'a, K, V> {
+    type Item = (&'a K, &'a mut V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next().map(|(k, v)| (&*k, v))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<K, V> DoubleEndedIterator for IterMut<'_, K, V> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back().map(|(k, v)| (&*k, v))
+    }
+}
+
+impl<K, V> ExactSizeIterator for IterMut<'_, K, V> {}
+
+impl<K, V> FusedIterator for IterMut<'_, K, V> {}
+
+impl<K, V, const L: usize> IntoIterator for ArrayMap<K, V, L> {
+    type Item = (K, V);
+    type IntoIter = crate::IntoIter<(K, V), L>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+impl<'a, K, V, const L: usize> IntoIterator for &'a ArrayMap<K, V, L> {
+    type Item = (&'a K, &'a V);
+    type IntoIter = Iter<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, K, V, const L: usize> IntoIterator for &'a mut ArrayMap<K, V, L> {
+    type Item = (&'a K, &'a mut V);
+    type IntoIter = IterMut<'a, K, V>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<K: Ord, V, const L: usize> FromIterator<(K, V)> for ArrayMap<K, V, L> {
+    /// Creates a map from an iterator of key-value pairs. Later values overwrite earlier ones with
+    /// the same key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator yields more than `L` distinct keys.
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<K: Ord, V, const L: usize> Extend<(K, V)> for ArrayMap<K, V, L> {
+    /// Inserts the key-value pairs of an iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the map overflows.
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (k, v) in iter {
+            if let Err(err) = self.insert(k, v) {
+                panic!("{}", err);
+            }
+        }
+    }
+}
+
+impl<K, Q, V, const L: usize> Index<&Q> for ArrayMap<K, V, L>
+where
+    K: Ord + Borrow<Q>,
+    Q: Ord + ?Sized,
+{
+    type Output = V;
+
+    /// Returns a reference to the value for `key`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the key is not present.
+    fn index(&self, key: &Q) -> &Self::Output {
+        self.get(key).expect("key not found")
+    }
+}
+
+impl<K: Clone, V: Clone, const L: usize> Clone for ArrayMap<K, V, L> {
+    fn clone(&self) -> Self {
+        Self {
+            entries: self.entries.clone(),
+        }
+    }
+}
+
+impl<K: Debug, V: Debug, const L: usize> Debug for ArrayMap<K, V, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: PartialEq, V: PartialEq, const L: usize, const M: usize> PartialEq<ArrayMap<K, V, M>>
+    for ArrayMap<K, V, L>
+{
+    fn eq(&self, other: &ArrayMap<K, V, M>) -> bool {
+        self.entries == other.entries
+    }
+}
+
+impl<K: Eq, V: Eq, const L: usize> Eq for ArrayMap<K, V, L> {}
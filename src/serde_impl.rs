@@ -0,0 +1,86 @@
+use core::{fmt, marker::PhantomData};
+
+use serde::{
+    de::{self, Deserialize, Deserializer, SeqAccess, Visitor},
+    ser::{Serialize, SerializeSeq, Serializer},
+};
+
+use crate::{Array, ArrayString};
+
+impl<T: Serialize, const L: usize> Serialize for Array<T, L> {
+    /// Serializes the live elements as a sequence.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for elt in self.iter() {
+            seq.serialize_element(elt)?;
+        }
+        seq.end()
+    }
+}
+
+impl<'de, T: Deserialize<'de>, const L: usize> Deserialize<'de> for Array<T, L> {
+    /// Deserializes a sequence of at most `L` elements, returning an `invalid_length` error with the
+    /// input's length if it holds more.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ArrayVisitor<T, const L: usize>(PhantomData<T>);
+
+        impl<'de, T: Deserialize<'de>, const L: usize> Visitor<'de> for ArrayVisitor<T, L> {
+            type Value = Array<T, L>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a sequence of at most {} elements", L)
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut arr = Array::default();
+                while let Some(elt) = seq.next_element()? {
+                    if arr.try_push(elt).is_err() {
+                        // Skip the rest so the error reports the actual length of the input.
+                        let mut len = L + 1;
+                        while seq.next_element::<de::IgnoredAny>()?.is_some() {
+                            len += 1;
+                        }
+                        return Err(de::Error::invalid_length(len, &self));
+                    }
+                }
+                Ok(arr)
+            }
+        }
+
+        deserializer.deserialize_seq(ArrayVisitor::<T, L>(PhantomData))
+    }
+}
+
+impl<const L: usize> Serialize for ArrayString<L> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de, const L: usize> Deserialize<'de> for ArrayString<L> {
+    /// Deserializes a string of at most `L` bytes, returning an `invalid_length` error if the input
+    /// is longer.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ArrayStringVisitor<const L: usize>;
+
+        impl<const L: usize> Visitor<'_> for ArrayStringVisitor<L> {
+            type Value = ArrayString<L>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a string of at most {} bytes", L)
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+                ArrayString::try_from_str(v).map_err(|_| E::invalid_length(v.len(), &self))
+            }
+
+            fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+                let s = core::str::from_utf8(v)
+                    .map_err(|_| E::invalid_value(de::Unexpected::Bytes(v), &self))?;
+                self.visit_str(s)
+            }
+        }
+
+        deserializer.deserialize_str(ArrayStringVisitor::<L>)
+    }
+}
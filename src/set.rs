@@ -0,0 +1,358 @@
+use core::{
+    borrow::Borrow,
+    cmp::Ordering,
+    fmt::{self, Debug},
+    slice,
+};
+
+use crate::{Array, CapacityError};
+
+/// Fixed-capacity ordered set storing up to `L` elements inline in a sorted [`Array`].
+///
+/// Lookups are O(log n) binary searches, and the set operations are O(n + m) merges.
+///
+/// # Examples
+///
+/// ```
+/// use arrayy::ArraySet;
+/// let mut set = ArraySet::<u32, 4>::new();
+/// assert_eq!(set.insert(3), Ok(true));
+/// assert_eq!(set.insert(1), Ok(true));
+/// assert_eq!(set.insert(3), Ok(false));
+/// assert!(set.contains(&1));
+/// assert_eq!(set.as_slice(), &[1, 3]);
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct ArraySet<T, const L: usize> {
+    items: Array<T, L>,
+}
+
+impl<T, const L: usize> Default for ArraySet<T, L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const L: usize> ArraySet<T, L> {
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        Self {
+            items: Array::default(),
+        }
+    }
+
+    pub const fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub const fn capacity(&self) -> usize {
+        L
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Clears the set, removing all elements.
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+
+    /// Returns the elements as a sorted slice.
+    pub fn as_slice(&self) -> &[T] {
+        self.items.as_slice()
+    }
+
+    /// Returns an iterator over the elements in ascending order.
+    pub fn iter(&self) -> slice::Iter<'_, T> {
+        self.items.as_slice().iter()
+    }
+
+    pub fn first(&self) -> Option<&T> {
+        self.items.first()
+    }
+
+    pub fn last(&self) -> Option<&T> {
+        self.items.last()
+    }
+
+    /// Removes and returns the smallest element.
+    pub fn pop_first(&mut self) -> Option<T> {
+        if self.items.is_empty() {
+            None
+        } else {
+            Some(self.items.remove(0))
+        }
+    }
+
+    /// Removes and returns the largest element.
+    pub fn pop_last(&mut self) -> Option<T> {
+        self.items.pop()
+    }
+
+    /// Retains only the elements that match the predicate.
+    pub fn retain(&mut self, f: impl FnMut(&T) -> bool) {
+        self.items.retain(f);
+    }
+}
+
+impl<T: Ord, const L: usize> ArraySet<T, L> {
+    fn search<Q>(&self, value: &Q) -> Result<usize, usize>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.items.binary_search_by(|elt| elt.borrow().cmp(value))
+    }
+
+    /// Adds a value to the set, returning whether it was newly inserted, or returns it in a
+    /// [`CapacityError`] if it is new and the set is full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::ArraySet;
+    /// let mut set = ArraySet::<u32, 1>::new();
+    /// assert_eq!(set.insert(1), Ok(true));
+    /// assert_eq!(set.insert(1), Ok(false));
+    /// assert_eq!(set.insert(2).unwrap_err().element(), 2);
+    /// ```
+    pub fn insert(&mut self, value: T) -> Result<bool, CapacityError<T>> {
+        match self.search(&value) {
+            Ok(_) => Ok(false),
+            Err(i) => self.items.try_insert(i, value).map(|()| true),
+        }
+    }
+
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.search(value).is_ok()
+    }
+
+    /// Returns a reference to the stored value equal to `value`, if any.
+    pub fn get<Q>(&self, value: &Q) -> Option<&T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let i = self.search(value).ok()?;
+        Some(unsafe { self.items.get_unchecked(i) })
+    }
+
+    /// Removes `value` from the set, returning whether it was present.
+    pub fn remove<Q>(&mut self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.take(value).is_some()
+    }
+
+    /// Removes and returns the stored value equal to `value`, if any.
+    pub fn take<Q>(&mut self, value: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let i = self.search(value).ok()?;
+        Some(self.items.remove(i))
+    }
+
+    /// Returns `true` if every element of `self` is in `other`.
+    pub fn is_subset<const M: usize>(&self, other: &ArraySet<T, M>) -> bool {
+        let mut other = other.iter().peekable();
+        self.iter().all(|x| loop {
+            match other.peek().map(|y| x.cmp(y)) {
+                Some(Ordering::Greater) => {
+                    other.next();
+                }
+                Some(Ordering::Equal) => {
+                    other.next();
+                    break true;
+                }
+                _ => break false,
+            }
+        })
+    }
+
+    /// Returns `true` if every element of `other` is in `self`.
+    pub fn is_superset<const M: usize>(&self, other: &ArraySet<T, M>) -> bool {
+        other.is_subset(self)
+    }
+
+    /// Returns `true` if `self` and `other` have no elements in common.
+    pub fn is_disjoint<const M: usize>(&self, other: &ArraySet<T, M>) -> bool {
+        let mut merged = true;
+        merge(self.as_slice(), other.as_slice(), |_, which| {
+            if which == Which::Both {
+                merged = false;
+            }
+            merged
+        });
+        merged
+    }
+}
+
+impl<T: Ord + Clone, const L: usize> ArraySet<T, L> {
+    fn collect<const M: usize, const N: usize>(
+        &self,
+        other: &ArraySet<T, M>,
+        keep: impl Fn(Which) -> bool,
+    ) -> Result<ArraySet<T, N>, CapacityError<T>> {
+        let mut out = ArraySet::<T, N>::new();
+        let mut result = Ok(());
+        merge(self.as_slice(), other.as_slice(), |elt, which| {
+            if keep(which) {
+                result = out.items.try_push(elt.clone());
+            }
+            result.is_ok()
+        });
+        result.map(|()| out)
+    }
+
+    /// Returns the elements in `self` or `other` as a new set of capacity `N`, or the first
+    /// element that did not fit in a [`CapacityError`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::ArraySet;
+    /// let a: ArraySet<u32, 4> = [1, 2, 3].into_iter().collect();
+    /// let b: ArraySet<u32, 4> = [2, 4].into_iter().collect();
+    /// let u: ArraySet<u32, 8> = a.union(&b).unwrap();
+    /// assert_eq!(u.as_slice(), &[1, 2, 3, 4]);
+    /// assert_eq!(a.union::<4, 3>(&b).unwrap_err().element(), 4);
+    /// ```
+    pub fn union<const M: usize, const N: usize>(
+        &self,
+        other: &ArraySet<T, M>,
+    ) -> Result<ArraySet<T, N>, CapacityError<T>> {
+        self.collect(other, |_| true)
+    }
+
+    /// Returns the elements in both `self` and `other` as a new set of capacity `N`, or the first
+    /// element that did not fit in a [`CapacityError`].
+    pub fn intersection<const M: usize, const N: usize>(
+        &self,
+        other: &ArraySet<T, M>,
+    ) -> Result<ArraySet<T, N>, CapacityError<T>> {
+        self.collect(other, |which| which == Which::Both)
+    }
+
+    /// Returns the elements in `self` but not in `other` as a new set of capacity `N`, or the
+    /// first element that did not fit in a [`CapacityError`].
+    pub fn difference<const M: usize, const N: usize>(
+        &self,
+        other: &ArraySet<T, M>,
+    ) -> Result<ArraySet<T, N>, CapacityError<T>> {
+        self.collect(other, |which| which == Which::Left)
+    }
+
+    /// Returns the elements in exactly one of `self` and `other` as a new set of capacity `N`, or
+    /// the first element that did not fit in a [`CapacityError`].
+    pub fn symmetric_difference<const M: usize, const N: usize>(
+        &self,
+        other: &ArraySet<T, M>,
+    ) -> Result<ArraySet<T, N>, CapacityError<T>> {
+        self.collect(other, |which| which != Which::Both)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Which {
+    Left,
+    Right,
+    Both,
+}
+
+/// Walks two sorted slices in order, calling `f` with each distinct element and which side it
+/// came from, until `f` returns `false`.
+fn merge<T: Ord>(a: &[T], b: &[T], mut f: impl FnMut(&T, Which) -> bool) {
+    let (mut i, mut j) = (0, 0);
+    loop {
+        let go_on = match (a.get(i), b.get(j)) {
+            (Some(x), Some(y)) => match x.cmp(y) {
+                Ordering::Less => {
+                    i += 1;
+                    f(x, Which::Left)
+                }
+                Ordering::Greater => {
+                    j += 1;
+                    f(y, Which::Right)
+                }
+                Ordering::Equal => {
+                    i += 1;
+                    j += 1;
+                    f(x, Which::Both)
+                }
+            },
+            (Some(x), None) => {
+                i += 1;
+                f(x, Which::Left)
+            }
+            (None, Some(y)) => {
+                j += 1;
+                f(y, Which::Right)
+            }
+            (None, None) => return,
+        };
+        if !go_on {
+            return;
+        }
+    }
+}
+
+impl<T, const L: usize> IntoIterator for ArraySet<T, L> {
+    type Item = T;
+    type IntoIter = crate::IntoIter<T, L>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a, T, const L: usize> IntoIterator for &'a ArraySet<T, L> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<T: Ord, const L: usize> FromIterator<T> for ArraySet<T, L> {
+    /// Creates a set from an iterator, ignoring duplicates.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator yields more than `L` distinct elements.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Ord, const L: usize> Extend<T> for ArraySet<T, L> {
+    /// Inserts the elements of an iterator, ignoring duplicates.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the set overflows.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for elt in iter {
+            if let Err(err) = self.insert(elt) {
+                panic!("{}", err);
+            }
+        }
+    }
+}
+
+impl<T: Debug, const L: usize> Debug for ArraySet<T, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,284 @@
+//! Fixed-capacity slab with stable keys backed by an [`Array`].
+
+use core::{
+    fmt::{self, Debug},
+    mem,
+    ops::{Index, IndexMut},
+};
+
+use crate::{Array, CapacityError};
+
+#[derive(Clone)]
+enum Slot<T> {
+    Occupied(T),
+    /// Key of the next vacant slot in the free list.
+    Vacant(usize),
+}
+
+/// Fixed-capacity slab storing up to `L` elements inline, addressed by stable `usize` keys.
+///
+/// Inserting and removing are O(1): removed slots are threaded onto a free list embedded in the
+/// storage and reused by later inserts, so no other element moves and keys stay valid until
+/// their element is removed.
+///
+/// # Examples
+///
+/// ```
+/// use arrayy::ArraySlab;
+///
+/// let mut slab = ArraySlab::<&str, 4>::new();
+/// let a = slab.insert("a");
+/// let b = slab.insert("b");
+/// assert_eq!(slab.remove(a), "a");
+/// assert_eq!(slab[b], "b");
+/// // the freed slot is reused
+/// assert_eq!(slab.insert("c"), a);
+/// ```
+pub struct ArraySlab<T, const L: usize> {
+    slots: Array<Slot<T>, L>,
+    /// Head of the free list, or `slots.len()` if every slot is occupied.
+    next: usize,
+    len: usize,
+}
+
+impl<T, const L: usize> Default for ArraySlab<T, L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const L: usize> ArraySlab<T, L> {
+    /// Creates an empty slab.
+    pub fn new() -> Self {
+        Self {
+            slots: Array::default(),
+            next: 0,
+            len: 0,
+        }
+    }
+
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    pub const fn capacity(&self) -> usize {
+        L
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub const fn is_full(&self) -> bool {
+        self.len == L
+    }
+
+    /// Removes all elements. Keys handed out before are invalidated.
+    pub fn clear(&mut self) {
+        self.slots.clear();
+        self.next = 0;
+        self.len = 0;
+    }
+
+    /// Returns the key the next insert will use, or `None` if the slab is full.
+    pub fn vacant_key(&self) -> Option<usize> {
+        if self.is_full() {
+            None
+        } else {
+            Some(self.next)
+        }
+    }
+
+    /// Inserts an element and returns its key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the slab is full.
+    pub fn insert(&mut self, val: T) -> usize {
+        match self.try_insert(val) {
+            Ok(key) => key,
+            Err(err) => panic!("{}", err),
+        }
+    }
+
+    /// Inserts an element and returns its key, or returns the element in a [`CapacityError`] if
+    /// the slab is full.
+    pub fn try_insert(&mut self, val: T) -> Result<usize, CapacityError<T>> {
+        if self.is_full() {
+            return Err(CapacityError::new(val, self.len, L));
+        }
+        let key = self.next;
+        if key == self.slots.len() {
+            unsafe { self.slots.push_unchecked(Slot::Occupied(val)) };
+            self.next += 1;
+        } else {
+            match mem::replace(&mut self.slots[key], Slot::Occupied(val)) {
+                Slot::Vacant(next) => self.next = next,
+                Slot::Occupied(_) => unreachable!("free list points at an occupied slot"),
+            }
+        }
+        self.len += 1;
+        Ok(key)
+    }
+
+    /// Returns a handle to the slot the next insert will use, which allows the key to be known
+    /// before the element is built. Returns `None` if the slab is full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::ArraySlab;
+    /// let mut slab = ArraySlab::<(usize, &str), 4>::new();
+    /// let entry = slab.vacant_entry().unwrap();
+    /// let key = entry.key();
+    /// entry.insert((key, "self"));
+    /// assert_eq!(slab[key].0, key);
+    /// ```
+    pub fn vacant_entry(&mut self) -> Option<VacantEntry<'_, T, L>> {
+        let key = self.vacant_key()?;
+        Some(VacantEntry { slab: self, key })
+    }
+
+    pub fn get(&self, key: usize) -> Option<&T> {
+        match self.slots.get(key)? {
+            Slot::Occupied(val) => Some(val),
+            Slot::Vacant(_) => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, key: usize) -> Option<&mut T> {
+        match self.slots.get_mut(key)? {
+            Slot::Occupied(val) => Some(val),
+            Slot::Vacant(_) => None,
+        }
+    }
+
+    /// Returns `true` if `key` refers to an element.
+    pub fn contains(&self, key: usize) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Removes and returns the element at `key`, or `None` if the slot is vacant.
+    pub fn try_remove(&mut self, key: usize) -> Option<T> {
+        let slot = self.slots.get_mut(key)?;
+        if let Slot::Vacant(_) = slot {
+            return None;
+        }
+        match mem::replace(slot, Slot::Vacant(self.next)) {
+            Slot::Occupied(val) => {
+                self.next = key;
+                self.len -= 1;
+                Some(val)
+            }
+            Slot::Vacant(_) => unreachable!(),
+        }
+    }
+
+    /// Removes and returns the element at `key`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `key` does not refer to an element.
+    pub fn remove(&mut self, key: usize) -> T {
+        self.try_remove(key).expect("invalid slab key")
+    }
+
+    /// Retains only the elements that match the predicate.
+    pub fn retain(&mut self, mut f: impl FnMut(usize, &mut T) -> bool) {
+        for key in 0..self.slots.len() {
+            if let Slot::Occupied(val) = &mut self.slots[key] {
+                if !f(key, val) {
+                    self.try_remove(key);
+                }
+            }
+        }
+    }
+
+    /// Returns an iterator over the occupied slots as `(key, &element)` pairs, in key order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (usize, &T)> {
+        self.slots
+            .as_slice()
+            .iter()
+            .enumerate()
+            .filter_map(|(key, slot)| match slot {
+                Slot::Occupied(val) => Some((key, val)),
+                Slot::Vacant(_) => None,
+            })
+    }
+
+    /// Returns an iterator over the occupied slots as `(key, &mut element)` pairs, in key order.
+    pub fn iter_mut(&mut self) -> impl DoubleEndedIterator<Item = (usize, &mut T)> {
+        self.slots
+            .as_mut_slice()
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(key, slot)| match slot {
+                Slot::Occupied(val) => Some((key, val)),
+                Slot::Vacant(_) => None,
+            })
+    }
+}
+
+/// A vacant slot of an [`ArraySlab`].
+///
+/// Created by [`ArraySlab::vacant_entry`].
+pub struct VacantEntry<'a, T, const L: usize> {
+    slab: &'a mut ArraySlab<T, L>,
+    key: usize,
+}
+
+impl<'a, T, const L: usize> VacantEntry<'a, T, L> {
+    /// Returns the key the element will be inserted at.
+    pub fn key(&self) -> usize {
+        self.key
+    }
+
+    /// Inserts the element and returns a mutable reference to it.
+    pub fn insert(self, val: T) -> &'a mut T {
+        let key = self.slab.insert(val);
+        debug_assert_eq!(key, self.key);
+        self.slab.get_mut(key).unwrap()
+    }
+}
+
+impl<T: Debug, const L: usize> Debug for VacantEntry<'_, T, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("VacantEntry").field(&self.key).finish()
+    }
+}
+
+impl<T, const L: usize> Index<usize> for ArraySlab<T, L> {
+    type Output = T;
+
+    /// # Panics
+    ///
+    /// Panics if `key` does not refer to an element.
+    fn index(&self, key: usize) -> &Self::Output {
+        self.get(key).expect("invalid slab key")
+    }
+}
+
+impl<T, const L: usize> IndexMut<usize> for ArraySlab<T, L> {
+    /// # Panics
+    ///
+    /// Panics if `key` does not refer to an element.
+    fn index_mut(&mut self, key: usize) -> &mut Self::Output {
+        self.get_mut(key).expect("invalid slab key")
+    }
+}
+
+impl<T: Clone, const L: usize> Clone for ArraySlab<T, L> {
+    fn clone(&self) -> Self {
+        Self {
+            slots: self.slots.clone(),
+            next: self.next,
+            len: self.len,
+        }
+    }
+}
+
+impl<T: Debug, const L: usize> Debug for ArraySlab<T, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
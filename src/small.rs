@@ -0,0 +1,486 @@
+//! Inline array that spills to the heap once it outgrows its capacity.
+
+use alloc::vec::{self, Vec};
+use core::{
+    fmt::{self, Debug},
+    hash::{Hash, Hasher},
+    mem,
+    ops::{Deref, DerefMut},
+    slice,
+};
+
+use crate::Array;
+
+enum Repr<T, const L: usize> {
+    Inline(Array<T, L>),
+    Heap(Vec<T>),
+}
+
+/// Growable array that stores up to `L` elements inline and moves them into a [`Vec`] once more
+/// are pushed.
+///
+/// # Examples
+///
+/// ```
+/// use arrayy::SmallArray;
+///
+/// let mut arr = SmallArray::<u32, 2>::new();
+/// arr.push(1);
+/// arr.push(2);
+/// assert!(!arr.spilled());
+/// arr.push(3);
+/// assert!(arr.spilled());
+/// assert_eq!(arr, [1, 2, 3]);
+///
+/// arr.pop();
+/// arr.shrink_to_inline();
+/// assert!(!arr.spilled());
+/// ```
+pub struct SmallArray<T, const L: usize> {
+    repr: Repr<T, L>,
+}
+
+impl<T, const L: usize> Default for SmallArray<T, L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const L: usize> SmallArray<T, L> {
+    /// Creates an empty inline array.
+    pub fn new() -> Self {
+        Self {
+            repr: Repr::Inline(Array::default()),
+        }
+    }
+
+    /// Returns `true` if the elements have been moved to the heap.
+    pub const fn spilled(&self) -> bool {
+        matches!(self.repr, Repr::Heap(_))
+    }
+
+    pub fn len(&self) -> usize {
+        match &self.repr {
+            Repr::Inline(arr) => arr.len(),
+            Repr::Heap(vec) => vec.len(),
+        }
+    }
+
+    /// Returns the number of elements that fit without reallocating: `L` while inline, the
+    /// capacity of the vector once spilled.
+    pub fn capacity(&self) -> usize {
+        match &self.repr {
+            Repr::Inline(_) => L,
+            Repr::Heap(vec) => vec.capacity(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        match &self.repr {
+            Repr::Inline(arr) => arr.as_slice(),
+            Repr::Heap(vec) => vec.as_slice(),
+        }
+    }
+
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        match &mut self.repr {
+            Repr::Inline(arr) => arr.as_mut_slice(),
+            Repr::Heap(vec) => vec.as_mut_slice(),
+        }
+    }
+
+    /// Returns the vector, moving the inline elements to the heap if they do not fit `additional`
+    /// more elements.
+    fn reserve(&mut self, additional: usize) -> Option<&mut Vec<T>> {
+        if let Repr::Inline(arr) = &mut self.repr {
+            let len = arr.len();
+            if len + additional <= L {
+                return None;
+            }
+            let mut vec = Vec::with_capacity((len + additional).max(L * 2));
+            vec.extend(mem::take(arr));
+            self.repr = Repr::Heap(vec);
+        }
+        match &mut self.repr {
+            Repr::Heap(vec) => Some(vec),
+            Repr::Inline(_) => unreachable!(),
+        }
+    }
+
+    /// Appends an element, spilling to the heap if the inline storage is full.
+    pub fn push(&mut self, val: T) {
+        match self.reserve(1) {
+            Some(vec) => vec.push(val),
+            None => match &mut self.repr {
+                Repr::Inline(arr) => unsafe { arr.push_unchecked(val) },
+                Repr::Heap(_) => unreachable!(),
+            },
+        }
+    }
+
+    pub fn pop(&mut self) -> Option<T> {
+        match &mut self.repr {
+            Repr::Inline(arr) => arr.pop(),
+            Repr::Heap(vec) => vec.pop(),
+        }
+    }
+
+    /// Inserts an element at `index`, shifting all elements after it to the right and spilling to
+    /// the heap if the inline storage is full.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    pub fn insert(&mut self, index: usize, element: T) {
+        let len = self.len();
+        if index > len {
+            panic!("insert index ({}) > len ({})", index, len);
+        }
+        match self.reserve(1) {
+            Some(vec) => vec.insert(index, element),
+            None => match &mut self.repr {
+                Repr::Inline(arr) => unsafe { arr.insert_unchecked(index, element) },
+                Repr::Heap(_) => unreachable!(),
+            },
+        }
+    }
+
+    /// Removes and returns the element at `index`, shifting all elements after it to the left.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    pub fn remove(&mut self, index: usize) -> T {
+        let len = self.len();
+        if index >= len {
+            panic!("remove index ({}) >= len ({})", index, len);
+        }
+        match &mut self.repr {
+            Repr::Inline(arr) => arr.remove(index),
+            Repr::Heap(vec) => vec.remove(index),
+        }
+    }
+
+    /// Removes and returns the element at `index`, replacing it with the last element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    pub fn swap_remove(&mut self, index: usize) -> T {
+        let len = self.len();
+        if index >= len {
+            panic!("swap_remove index ({}) >= len ({})", index, len);
+        }
+        match &mut self.repr {
+            Repr::Inline(arr) => arr.swap_remove(index),
+            Repr::Heap(vec) => vec.swap_remove(index),
+        }
+    }
+
+    pub fn truncate(&mut self, len: usize) {
+        match &mut self.repr {
+            Repr::Inline(arr) => arr.truncate(len),
+            Repr::Heap(vec) => vec.truncate(len),
+        }
+    }
+
+    /// Removes all elements. Spilled storage is kept; see [`shrink_to_inline`](Self::shrink_to_inline).
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Retains only the elements that match the predicate, removing the rest in place.
+    pub fn retain(&mut self, mut f: impl FnMut(&T) -> bool) {
+        match &mut self.repr {
+            Repr::Inline(arr) => arr.retain(|elt| f(elt)),
+            Repr::Heap(vec) => vec.retain(|elt| f(elt)),
+        }
+    }
+
+    /// Filters the elements, returning only the elements that match the predicate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::SmallArray;
+    /// let arr: SmallArray<u32, 2> = (1..=5).collect();
+    /// assert_eq!(arr.filter(|&x| x % 2 == 0), [2, 4]);
+    /// ```
+    pub fn filter(mut self, predicate: impl FnMut(&T) -> bool) -> Self {
+        self.retain(predicate);
+        self
+    }
+
+    /// Maps the elements to a new array with the same inline capacity, which stays spilled if
+    /// this one is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::SmallArray;
+    /// let arr: SmallArray<u32, 2> = (1..=3).collect();
+    /// assert_eq!(arr.map(|&x| x * 2), [2, 4, 6]);
+    /// ```
+    pub fn map<X>(self, mut f: impl FnMut(&T) -> X) -> SmallArray<X, L> {
+        let repr = match self.repr {
+            Repr::Inline(arr) => Repr::Inline(arr.map(f)),
+            Repr::Heap(vec) => Repr::Heap(vec.iter().map(&mut f).collect()),
+        };
+        SmallArray { repr }
+    }
+
+    /// Moves the elements of an array to the end, spilling to the heap if they do not fit inline.
+    pub fn append<const M: usize>(&mut self, other: Array<T, M>) {
+        match self.reserve(other.len()) {
+            Some(vec) => vec.extend(other),
+            None => match &mut self.repr {
+                Repr::Inline(arr) => unsafe { arr.append_unchecked(other) },
+                Repr::Heap(_) => unreachable!(),
+            },
+        }
+    }
+
+    /// Moves the elements back into inline storage if they fit, freeing the heap allocation.
+    pub fn shrink_to_inline(&mut self) {
+        if let Repr::Heap(vec) = &mut self.repr {
+            if vec.len() <= L {
+                let arr = mem::take(vec).into_iter().collect();
+                self.repr = Repr::Inline(arr);
+            }
+        }
+    }
+
+    /// Converts into an [`Array`] if the elements fit inline, or returns `self` otherwise.
+    pub fn into_array(mut self) -> Result<Array<T, L>, Self> {
+        self.shrink_to_inline();
+        match self.repr {
+            Repr::Inline(arr) => Ok(arr),
+            repr => Err(Self { repr }),
+        }
+    }
+
+    pub fn into_vec(self) -> Vec<T> {
+        match self.repr {
+            Repr::Inline(arr) => arr.into(),
+            Repr::Heap(vec) => vec,
+        }
+    }
+}
+
+impl<T: Clone, const L: usize> SmallArray<T, L> {
+    /// Appends the elements of a slice, spilling to the heap if they do not fit inline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::SmallArray;
+    /// let mut arr = SmallArray::<u32, 4>::from([1, 2].as_slice());
+    /// arr.append_slice(&[3, 4, 5]);
+    /// assert!(arr.spilled());
+    /// assert_eq!(arr, [1, 2, 3, 4, 5]);
+    /// ```
+    pub fn append_slice(&mut self, other: &[T]) {
+        match self.reserve(other.len()) {
+            Some(vec) => vec.extend_from_slice(other),
+            None => match &mut self.repr {
+                Repr::Inline(arr) => unsafe { arr.append_slice_unchecked(other) },
+                Repr::Heap(_) => unreachable!(),
+            },
+        }
+    }
+}
+
+impl<T, const L: usize> From<Array<T, L>> for SmallArray<T, L> {
+    fn from(arr: Array<T, L>) -> Self {
+        Self {
+            repr: Repr::Inline(arr),
+        }
+    }
+}
+
+impl<T, const L: usize> From<Vec<T>> for SmallArray<T, L> {
+    /// Takes ownership of the vector, moving its elements inline if they fit.
+    fn from(vec: Vec<T>) -> Self {
+        let mut arr = Self {
+            repr: Repr::Heap(vec),
+        };
+        arr.shrink_to_inline();
+        arr
+    }
+}
+
+impl<T: Clone, const L: usize> From<&[T]> for SmallArray<T, L> {
+    fn from(slice: &[T]) -> Self {
+        let mut arr = Self::new();
+        arr.append_slice(slice);
+        arr
+    }
+}
+
+impl<T, const L: usize> From<SmallArray<T, L>> for Vec<T> {
+    fn from(arr: SmallArray<T, L>) -> Self {
+        arr.into_vec()
+    }
+}
+
+impl<T, const L: usize> Deref for SmallArray<T, L> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.as_slice()
+    }
+}
+
+impl<T, const L: usize> DerefMut for SmallArray<T, L> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_slice()
+    }
+}
+
+impl<T, const L: usize> AsRef<[T]> for SmallArray<T, L> {
+    fn as_ref(&self) -> &[T] {
+        self.as_slice()
+    }
+}
+
+impl<T, const L: usize> AsMut<[T]> for SmallArray<T, L> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.as_mut_slice()
+    }
+}
+
+impl<T: Clone, const L: usize> Clone for SmallArray<T, L> {
+    fn clone(&self) -> Self {
+        let repr = match &self.repr {
+            Repr::Inline(arr) => Repr::Inline(arr.clone()),
+            Repr::Heap(vec) => Repr::Heap(vec.clone()),
+        };
+        Self { repr }
+    }
+}
+
+impl<T: Debug, const L: usize> Debug for SmallArray<T, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, const L: usize, const M: usize> PartialEq<SmallArray<T, M>>
+    for SmallArray<T, L>
+{
+    fn eq(&self, other: &SmallArray<T, M>) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Eq, const L: usize> Eq for SmallArray<T, L> {}
+
+impl<T: PartialEq, const L: usize> PartialEq<[T]> for SmallArray<T, L> {
+    fn eq(&self, other: &[T]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: PartialEq, const L: usize, const N: usize> PartialEq<[T; N]> for SmallArray<T, L> {
+    fn eq(&self, other: &[T; N]) -> bool {
+        self.as_slice() == other
+    }
+}
+
+impl<T: Hash, const L: usize> Hash for SmallArray<T, L> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
+impl<T, const L: usize> FromIterator<T> for SmallArray<T, L> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut arr = Self::new();
+        arr.extend(iter);
+        arr
+    }
+}
+
+impl<T, const L: usize> Extend<T> for SmallArray<T, L> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for item in iter {
+            self.push(item);
+        }
+    }
+}
+
+/// An iterator that moves out of a [`SmallArray`].
+///
+/// Created by the `into_iter` method on [`SmallArray`].
+pub struct IntoIter<T, const L: usize> {
+    inner: IntoIterRepr<T, L>,
+}
+
+enum IntoIterRepr<T, const L: usize> {
+    Inline(crate::IntoIter<T, L>),
+    Heap(vec::IntoIter<T>),
+}
+
+impl<T, const L: usize> Iterator for IntoIter<T, L> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        match &mut self.inner {
+            IntoIterRepr::Inline(iter) => iter.next(),
+            IntoIterRepr::Heap(iter) => iter.next(),
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.inner {
+            IntoIterRepr::Inline(iter) => iter.size_hint(),
+            IntoIterRepr::Heap(iter) => iter.size_hint(),
+        }
+    }
+}
+
+impl<T, const L: usize> DoubleEndedIterator for IntoIter<T, L> {
+    fn next_back(&mut self) -> Option<T> {
+        match &mut self.inner {
+            IntoIterRepr::Inline(iter) => iter.next_back(),
+            IntoIterRepr::Heap(iter) => iter.next_back(),
+        }
+    }
+}
+
+impl<T, const L: usize> ExactSizeIterator for IntoIter<T, L> {}
+
+impl<T, const L: usize> IntoIterator for SmallArray<T, L> {
+    type Item = T;
+    type IntoIter = IntoIter<T, L>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let inner = match self.repr {
+            Repr::Inline(arr) => IntoIterRepr::Inline(arr.into_iter()),
+            Repr::Heap(vec) => IntoIterRepr::Heap(vec.into_iter()),
+        };
+        IntoIter { inner }
+    }
+}
+
+impl<'a, T, const L: usize> IntoIterator for &'a SmallArray<T, L> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_slice().iter()
+    }
+}
+
+impl<'a, T, const L: usize> IntoIterator for &'a mut SmallArray<T, L> {
+    type Item = &'a mut T;
+    type IntoIter = slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_mut_slice().iter_mut()
+    }
+}
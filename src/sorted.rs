@@ -0,0 +1,323 @@
+use core::{
+    borrow::Borrow,
+    fmt::{self, Debug},
+    ops::{Bound, Deref, RangeBounds},
+    slice,
+};
+
+use crate::{Array, CapacityCheck, CapacityError};
+
+/// Fixed-capacity array storing up to `L` elements inline, kept in ascending order.
+///
+/// Unlike [`ArraySet`](crate::ArraySet), duplicates are allowed unless inserted with
+/// [`insert_dedup`](Self::insert_dedup). Read access goes through `Deref<Target = [T]>`, but there
+/// is no mutable slice access so the ordering cannot be broken.
+///
+/// # Examples
+///
+/// ```
+/// use arrayy::{array, SortedArray};
+///
+/// let mut arr = SortedArray::from(array!(5, 1, 3; 6));
+/// assert_eq!(arr.as_slice(), &[1, 3, 5]);
+/// arr.insert_sorted(4).unwrap();
+/// assert_eq!(arr.range(2..5), &[3, 4]);
+/// assert_eq!(arr.lower_bound(&4), 2);
+/// ```
+#[derive(Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct SortedArray<T, const L: usize> {
+    items: Array<T, L>,
+}
+
+impl<T, const L: usize> Default for SortedArray<T, L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const L: usize> SortedArray<T, L> {
+    /// Creates an empty sorted array.
+    pub fn new() -> Self {
+        Self {
+            items: Array::default(),
+        }
+    }
+
+    pub const fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub const fn capacity(&self) -> usize {
+        L
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    pub const fn is_full(&self) -> bool {
+        self.items.len() == L
+    }
+
+    pub fn as_slice(&self) -> &[T] {
+        self.items.as_slice()
+    }
+
+    /// Consumes the wrapper, returning the sorted array.
+    pub fn into_array(self) -> Array<T, L> {
+        self.items
+    }
+
+    /// Removes and returns the element at `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index >= len`.
+    pub fn remove(&mut self, index: usize) -> T {
+        self.items.remove(index)
+    }
+
+    /// Removes and returns the smallest element.
+    pub fn pop_first(&mut self) -> Option<T> {
+        if self.is_empty() {
+            None
+        } else {
+            Some(self.items.remove(0))
+        }
+    }
+
+    /// Removes and returns the greatest element.
+    pub fn pop_last(&mut self) -> Option<T> {
+        self.items.pop()
+    }
+
+    /// Shortens the array, keeping the `len` smallest elements.
+    pub fn truncate(&mut self, len: usize) {
+        self.items.truncate(len);
+    }
+
+    pub fn clear(&mut self) {
+        self.items.clear();
+    }
+
+    /// Retains only the elements that match the predicate.
+    pub fn retain(&mut self, f: impl FnMut(&T) -> bool) {
+        self.items.retain(f);
+    }
+}
+
+impl<T: Ord, const L: usize> SortedArray<T, L> {
+    /// Inserts an element after any equal elements and returns its index, or returns the element
+    /// in a [`CapacityError`] if the array is full.
+    pub fn insert_sorted(&mut self, element: T) -> Result<usize, CapacityError<T>> {
+        self.items.insert_sorted(element)
+    }
+
+    /// Inserts an element unless an equal one is already present. Returns `Ok(true)` if it was
+    /// inserted, `Ok(false)` if it was a duplicate (in which case it is dropped), or the element
+    /// in a [`CapacityError`] if the array is full.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::SortedArray;
+    /// let mut arr = SortedArray::<u32, 4>::new();
+    /// assert_eq!(arr.insert_dedup(2), Ok(true));
+    /// assert_eq!(arr.insert_dedup(2), Ok(false));
+    /// assert_eq!(arr.as_slice(), &[2]);
+    /// ```
+    pub fn insert_dedup(&mut self, element: T) -> Result<bool, CapacityError<T>> {
+        match self.items.binary_search(&element) {
+            Ok(_) => Ok(false),
+            Err(index) => self.items.try_insert(index, element).map(|()| true),
+        }
+    }
+
+    /// Removes and returns the first element equal to `value`.
+    pub fn remove_value<Q>(&mut self, value: &Q) -> Option<T>
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.lower_bound(value);
+        if index < self.len() && self.items[index].borrow() == value {
+            Some(self.items.remove(index))
+        } else {
+            None
+        }
+    }
+
+    pub fn contains<Q>(&self, value: &Q) -> bool
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.items
+            .binary_search_by(|elt| elt.borrow().cmp(value))
+            .is_ok()
+    }
+
+    /// Returns the index of the first element not less than `value`.
+    pub fn lower_bound<Q>(&self, value: &Q) -> usize
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.items.partition_point(|elt| elt.borrow() < value)
+    }
+
+    /// Returns the index of the first element greater than `value`.
+    pub fn upper_bound<Q>(&self, value: &Q) -> usize
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.items.partition_point(|elt| elt.borrow() <= value)
+    }
+
+    /// Returns the elements that lie in `range`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than its end.
+    pub fn range<Q, R>(&self, range: R) -> &[T]
+    where
+        T: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        let start = match range.start_bound() {
+            Bound::Included(q) => self.lower_bound(q),
+            Bound::Excluded(q) => self.upper_bound(q),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(q) => self.upper_bound(q),
+            Bound::Excluded(q) => self.lower_bound(q),
+            Bound::Unbounded => self.len(),
+        };
+        if start > end {
+            panic!("range start ({}) > end ({})", start, end);
+        }
+        &self.items[start..end]
+    }
+
+    /// Removes consecutive equal elements, leaving each value once.
+    pub fn dedup(&mut self) {
+        self.items.dedup();
+    }
+
+    /// Merges two sorted arrays into a sorted array of capacity `N`. Equal elements from `self`
+    /// come first.
+    ///
+    /// `L + M <= N` is checked at compile time, so this never fails at runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::SortedArray;
+    /// let a: SortedArray<u32, 3> = [5, 1, 3].into_iter().collect();
+    /// let b: SortedArray<u32, 2> = [4, 2].into_iter().collect();
+    /// let merged: SortedArray<u32, 5> = a.merge(b);
+    /// assert_eq!(merged.as_slice(), &[1, 2, 3, 4, 5]);
+    /// ```
+    pub fn merge<const M: usize, const N: usize>(
+        self,
+        other: SortedArray<T, M>,
+    ) -> SortedArray<T, N> {
+        let () = CapacityCheck::<L, M, N>::SUM_FITS;
+        let mut items = Array::default();
+        let mut left = self.items.into_iter().peekable();
+        let mut right = other.items.into_iter().peekable();
+        loop {
+            let next = match (left.peek(), right.peek()) {
+                (Some(a), Some(b)) if b < a => right.next(),
+                (Some(_), _) => left.next(),
+                (None, _) => right.next(),
+            };
+            match next {
+                Some(elt) => unsafe { items.push_unchecked(elt) },
+                None => break,
+            }
+        }
+        SortedArray { items }
+    }
+}
+
+impl<T: Ord, const L: usize> From<Array<T, L>> for SortedArray<T, L> {
+    /// Sorts the array in place.
+    fn from(mut items: Array<T, L>) -> Self {
+        items.sort_unstable();
+        Self { items }
+    }
+}
+
+impl<T, const L: usize> From<SortedArray<T, L>> for Array<T, L> {
+    fn from(arr: SortedArray<T, L>) -> Self {
+        arr.items
+    }
+}
+
+impl<T, const L: usize> Deref for SortedArray<T, L> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        self.items.as_slice()
+    }
+}
+
+impl<T, const L: usize> AsRef<[T]> for SortedArray<T, L> {
+    fn as_ref(&self) -> &[T] {
+        self.items.as_slice()
+    }
+}
+
+impl<T, const L: usize> IntoIterator for SortedArray<T, L> {
+    type Item = T;
+    type IntoIter = crate::IntoIter<T, L>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+}
+
+impl<'a, T, const L: usize> IntoIterator for &'a SortedArray<T, L> {
+    type Item = &'a T;
+    type IntoIter = slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.as_slice().iter()
+    }
+}
+
+impl<T: Ord, const L: usize> FromIterator<T> for SortedArray<T, L> {
+    /// Collects and sorts the elements.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the iterator yields more than `L` elements.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::from(iter.into_iter().collect::<Array<T, L>>())
+    }
+}
+
+impl<T: Ord, const L: usize> Extend<T> for SortedArray<T, L> {
+    /// Inserts the elements of an iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array overflows.
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for elt in iter {
+            if let Err(err) = self.insert_sorted(elt) {
+                panic!("{}", err);
+            }
+        }
+    }
+}
+
+impl<T: Debug, const L: usize> Debug for SortedArray<T, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.items.as_slice()).finish()
+    }
+}
@@ -0,0 +1,362 @@
+use core::{
+    fmt::{self, Debug},
+    ops::{Index, IndexMut},
+    slice,
+};
+
+use crate::{check_below, Array};
+
+/// Returns the dense position of `id`, validating the sparse entry against the dense ids so that
+/// stale entries left behind by removals and clears are ignored.
+fn dense_index<const L: usize>(dense: &[usize], sparse: &[usize; L], id: usize) -> Option<usize> {
+    let index = *sparse.get(id)?;
+    (dense.get(index) == Some(&id)).then_some(index)
+}
+
+/// Set of ids in `0..L`, stored as a dense [`Array`] of ids plus a sparse index table.
+///
+/// Inserting, removing and membership tests are O(1), iteration walks only the dense ids, and
+/// [`clear`](Self::clear) is O(1) because stale sparse entries are detected on lookup. Removal
+/// swaps the last id into the hole, so iteration order is not insertion order after a removal.
+///
+/// # Examples
+///
+/// ```
+/// use arrayy::SparseSet;
+///
+/// let mut set = SparseSet::<64>::new();
+/// set.insert(42);
+/// set.insert(7);
+/// set.insert(13);
+/// assert!(set.contains(7));
+/// set.remove(42);
+/// assert_eq!(set.as_slice(), &[13, 7]);
+/// ```
+#[derive(Clone)]
+pub struct SparseSet<const L: usize> {
+    dense: Array<usize, L>,
+    sparse: [usize; L],
+}
+
+impl<const L: usize> Default for SparseSet<L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const L: usize> SparseSet<L> {
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        Self {
+            dense: Array::default(),
+            sparse: [0; L],
+        }
+    }
+
+    pub const fn len(&self) -> usize {
+        self.dense.len()
+    }
+
+    /// Returns `L`, the number of distinct ids the set can hold.
+    pub const fn capacity(&self) -> usize {
+        L
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.dense.is_empty()
+    }
+
+    /// Removes all ids in O(1).
+    pub fn clear(&mut self) {
+        self.dense.clear();
+    }
+
+    /// Returns the ids in dense order.
+    pub fn as_slice(&self) -> &[usize] {
+        self.dense.as_slice()
+    }
+
+    /// Returns an iterator over the ids in dense order.
+    pub fn iter(&self) -> slice::Iter<'_, usize> {
+        self.dense.as_slice().iter()
+    }
+
+    /// Returns `true` if the set contains `id`. Ids outside `0..L` are never contained.
+    pub fn contains(&self, id: usize) -> bool {
+        dense_index(self.dense.as_slice(), &self.sparse, id).is_some()
+    }
+
+    /// Adds `id` to the set. Returns `true` if it was not already present.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id >= L`.
+    pub fn insert(&mut self, id: usize) -> bool {
+        check_below("id", id, "capacity", L);
+        if self.contains(id) {
+            return false;
+        }
+        self.sparse[id] = self.dense.len();
+        // `L` distinct ids always fit.
+        unsafe { self.dense.push_unchecked(id) };
+        true
+    }
+
+    /// Removes `id` from the set, moving the last id into its place. Returns `true` if it was
+    /// present.
+    pub fn remove(&mut self, id: usize) -> bool {
+        match dense_index(self.dense.as_slice(), &self.sparse, id) {
+            Some(index) => {
+                self.dense.swap_remove(index);
+                if let Some(&moved) = self.dense.get(index) {
+                    self.sparse[moved] = index;
+                }
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl<const L: usize> FromIterator<usize> for SparseSet<L> {
+    /// Creates a set from an iterator of ids.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an id is not less than `L`.
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<const L: usize> Extend<usize> for SparseSet<L> {
+    /// Inserts the ids of an iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an id is not less than `L`.
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        for id in iter {
+            self.insert(id);
+        }
+    }
+}
+
+impl<'a, const L: usize> IntoIterator for &'a SparseSet<L> {
+    type Item = &'a usize;
+    type IntoIter = slice::Iter<'a, usize>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<const L: usize> Debug for SparseSet<L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+/// Map from ids in `0..L` to values, stored as dense [`Array`]s of ids and values plus a sparse
+/// index table.
+///
+/// Lookups, inserts and removes are O(1), and the values can be walked as one dense slice. See
+/// [`SparseSet`] for the layout.
+///
+/// # Examples
+///
+/// ```
+/// use arrayy::SparseMap;
+///
+/// let mut positions = SparseMap::<(f32, f32), 64>::new();
+/// positions.insert(3, (0.0, 1.0));
+/// positions.insert(9, (2.0, 0.5));
+/// for (x, _) in positions.values_mut() {
+///     *x += 1.0;
+/// }
+/// assert_eq!(positions[9], (3.0, 0.5));
+/// ```
+pub struct SparseMap<V, const L: usize> {
+    ids: Array<usize, L>,
+    values: Array<V, L>,
+    sparse: [usize; L],
+}
+
+impl<V, const L: usize> Default for SparseMap<V, L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V, const L: usize> SparseMap<V, L> {
+    /// Creates an empty map.
+    pub fn new() -> Self {
+        Self {
+            ids: Array::default(),
+            values: Array::default(),
+            sparse: [0; L],
+        }
+    }
+
+    pub const fn len(&self) -> usize {
+        self.ids.len()
+    }
+
+    /// Returns `L`, the number of distinct ids the map can hold.
+    pub const fn capacity(&self) -> usize {
+        L
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.ids.is_empty()
+    }
+
+    /// Removes all entries. This is O(1) for values that do not need to be dropped.
+    pub fn clear(&mut self) {
+        self.ids.clear();
+        self.values.clear();
+    }
+
+    /// Returns the ids in dense order, matching [`values`](Self::values).
+    pub fn ids(&self) -> &[usize] {
+        self.ids.as_slice()
+    }
+
+    /// Returns the values in dense order, matching [`ids`](Self::ids).
+    pub fn values(&self) -> &[V] {
+        self.values.as_slice()
+    }
+
+    pub fn values_mut(&mut self) -> &mut [V] {
+        self.values.as_mut_slice()
+    }
+
+    /// Returns an iterator over `(id, &value)` pairs in dense order.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = (usize, &V)> + ExactSizeIterator {
+        self.ids
+            .as_slice()
+            .iter()
+            .copied()
+            .zip(self.values.as_slice())
+    }
+
+    /// Returns an iterator over `(id, &mut value)` pairs in dense order.
+    pub fn iter_mut(
+        &mut self,
+    ) -> impl DoubleEndedIterator<Item = (usize, &mut V)> + ExactSizeIterator {
+        self.ids
+            .as_slice()
+            .iter()
+            .copied()
+            .zip(self.values.as_mut_slice())
+    }
+
+    /// Returns `true` if the map has a value for `id`.
+    pub fn contains_key(&self, id: usize) -> bool {
+        dense_index(self.ids.as_slice(), &self.sparse, id).is_some()
+    }
+
+    pub fn get(&self, id: usize) -> Option<&V> {
+        let index = dense_index(self.ids.as_slice(), &self.sparse, id)?;
+        self.values.get(index)
+    }
+
+    pub fn get_mut(&mut self, id: usize) -> Option<&mut V> {
+        let index = dense_index(self.ids.as_slice(), &self.sparse, id)?;
+        self.values.get_mut(index)
+    }
+
+    /// Inserts a value for `id`, returning the previous value if there was one.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `id >= L`.
+    pub fn insert(&mut self, id: usize, value: V) -> Option<V> {
+        check_below("id", id, "capacity", L);
+        if let Some(old) = self.get_mut(id) {
+            return Some(core::mem::replace(old, value));
+        }
+        self.sparse[id] = self.ids.len();
+        // `L` distinct ids always fit.
+        unsafe {
+            self.ids.push_unchecked(id);
+            self.values.push_unchecked(value);
+        }
+        None
+    }
+
+    /// Removes the value for `id`, moving the last entry into its place.
+    pub fn remove(&mut self, id: usize) -> Option<V> {
+        let index = dense_index(self.ids.as_slice(), &self.sparse, id)?;
+        self.ids.swap_remove(index);
+        let value = self.values.swap_remove(index);
+        if let Some(&moved) = self.ids.get(index) {
+            self.sparse[moved] = index;
+        }
+        Some(value)
+    }
+}
+
+impl<V, const L: usize> Index<usize> for SparseMap<V, L> {
+    type Output = V;
+
+    /// # Panics
+    ///
+    /// Panics if there is no value for `id`.
+    fn index(&self, id: usize) -> &Self::Output {
+        self.get(id).expect("no value for id")
+    }
+}
+
+impl<V, const L: usize> IndexMut<usize> for SparseMap<V, L> {
+    /// # Panics
+    ///
+    /// Panics if there is no value for `id`.
+    fn index_mut(&mut self, id: usize) -> &mut Self::Output {
+        self.get_mut(id).expect("no value for id")
+    }
+}
+
+impl<V, const L: usize> FromIterator<(usize, V)> for SparseMap<V, L> {
+    /// Creates a map from an iterator of `(id, value)` pairs.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an id is not less than `L`.
+    fn from_iter<I: IntoIterator<Item = (usize, V)>>(iter: I) -> Self {
+        let mut map = Self::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl<V, const L: usize> Extend<(usize, V)> for SparseMap<V, L> {
+    /// Inserts the pairs of an iterator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if an id is not less than `L`.
+    fn extend<I: IntoIterator<Item = (usize, V)>>(&mut self, iter: I) {
+        for (id, value) in iter {
+            self.insert(id, value);
+        }
+    }
+}
+
+impl<V: Clone, const L: usize> Clone for SparseMap<V, L> {
+    fn clone(&self) -> Self {
+        Self {
+            ids: self.ids.clone(),
+            values: self.values.clone(),
+            sparse: self.sparse,
+        }
+    }
+}
+
+impl<V: Debug, const L: usize> Debug for SparseMap<V, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
@@ -0,0 +1,298 @@
+//! Lock-free single-producer single-consumer queue with fixed inline storage.
+//!
+//! Uses only `core` atomics, so it works without `std`.
+//!
+//! # Examples
+//!
+//! ```
+//! use arrayy::spsc::Queue;
+//! use std::thread;
+//!
+//! let mut queue = Queue::<u32, 4>::new();
+//! let (mut producer, mut consumer) = queue.split();
+//! thread::scope(|s| {
+//!     s.spawn(move || {
+//!         for i in 0..100 {
+//!             while producer.try_push(i).is_err() {}
+//!         }
+//!     });
+//!     let mut expected = 0;
+//!     while expected < 100 {
+//!         if let Some(i) = consumer.try_pop() {
+//!             assert_eq!(i, expected);
+//!             expected += 1;
+//!         }
+//!     }
+//! });
+//! ```
+
+use core::{
+    cell::UnsafeCell,
+    fmt::{self, Debug},
+    mem::MaybeUninit,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use crate::{uninit_buf, Array, CapacityError};
+
+/// Bounded queue holding up to `L` elements, shared by one [`Producer`] and one [`Consumer`].
+///
+/// The queue is split into its two halves with [`Queue::split`], which can then be moved to
+/// different threads.
+pub struct Queue<T, const L: usize> {
+    buf: UnsafeCell<[MaybeUninit<T>; L]>,
+    // Positions run over `0..2 * L` so a full queue can be told apart from an empty one without
+    // leaving a slot unused.
+    head: AtomicUsize,
+    tail: AtomicUsize,
+}
+
+// The producer only writes slots the consumer has released and vice versa; the atomics order
+// those accesses, so sharing the queue only requires the elements to be sendable.
+unsafe impl<T: Send, const L: usize> Sync for Queue<T, L> {}
+
+impl<T, const L: usize> Default for Queue<T, L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const L: usize> Queue<T, L> {
+    /// Creates an empty queue.
+    pub const fn new() -> Self {
+        Self {
+            buf: UnsafeCell::new(uninit_buf()),
+            head: AtomicUsize::new(0),
+            tail: AtomicUsize::new(0),
+        }
+    }
+
+    /// Splits the queue into its producer and consumer halves.
+    pub fn split(&mut self) -> (Producer<'_, T, L>, Consumer<'_, T, L>) {
+        (Producer { queue: self }, Consumer { queue: self })
+    }
+
+    /// Returns the number of elements in the queue. Only a snapshot if the queue is in use.
+    pub fn len(&self) -> usize {
+        // Read from a third thread, the two positions can come from different moments, so the
+        // distance between them is clamped to what the queue can actually hold.
+        distance::<L>(
+            self.head.load(Ordering::Acquire),
+            self.tail.load(Ordering::Acquire),
+        )
+        .min(L)
+    }
+
+    pub const fn capacity(&self) -> usize {
+        L
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.len() == L
+    }
+
+    fn slot(&self, pos: usize) -> *mut MaybeUninit<T> {
+        let index = if pos >= L { pos - L } else { pos };
+        unsafe { (self.buf.get() as *mut MaybeUninit<T>).add(index) }
+    }
+}
+
+const fn distance<const L: usize>(head: usize, tail: usize) -> usize {
+    if tail >= head {
+        tail - head
+    } else {
+        tail + 2 * L - head
+    }
+}
+
+const fn advance<const L: usize>(pos: usize, n: usize) -> usize {
+    let next = pos + n;
+    if next >= 2 * L {
+        next - 2 * L
+    } else {
+        next
+    }
+}
+
+impl<T, const L: usize> Drop for Queue<T, L> {
+    fn drop(&mut self) {
+        let (mut head, tail) = (*self.head.get_mut(), *self.tail.get_mut());
+        while head != tail {
+            unsafe { (*self.slot(head)).assume_init_drop() };
+            head = advance::<L>(head, 1);
+        }
+    }
+}
+
+impl<T, const L: usize> Debug for Queue<T, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Queue")
+            .field("len", &self.len())
+            .field("capacity", &L)
+            .finish()
+    }
+}
+
+/// The pushing half of a [`Queue`].
+///
+/// Created by [`Queue::split`].
+pub struct Producer<'a, T, const L: usize> {
+    queue: &'a Queue<T, L>,
+}
+
+impl<T, const L: usize> Producer<'_, T, L> {
+    /// Returns the number of slots that can be pushed without failing. May grow concurrently as
+    /// the consumer pops.
+    pub fn free(&self) -> usize {
+        L.saturating_sub(self.queue.len())
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.free() == 0
+    }
+
+    /// Pushes an element, or returns it in a [`CapacityError`] if the queue is full.
+    pub fn try_push(&mut self, element: T) -> Result<(), CapacityError<T>> {
+        let tail = self.queue.tail.load(Ordering::Relaxed);
+        let len = distance::<L>(self.queue.head.load(Ordering::Acquire), tail);
+        if len == L {
+            return Err(CapacityError::new(element, len, L));
+        }
+        unsafe { (*self.queue.slot(tail)).write(element) };
+        self.queue
+            .tail
+            .store(advance::<L>(tail, 1), Ordering::Release);
+        Ok(())
+    }
+
+    /// Pushes every element of `arr`, or returns it in a [`CapacityError`] if they do not all
+    /// fit. Nothing is pushed on error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::{array, spsc::Queue};
+    /// let mut queue = Queue::<u32, 4>::new();
+    /// let (mut producer, mut consumer) = queue.split();
+    /// producer.push_array(array!(1, 2, 3)).unwrap();
+    /// assert!(producer.push_array(array!(4, 5)).is_err());
+    /// assert_eq!(consumer.pop_array::<2>(), array!(1, 2));
+    /// ```
+    pub fn push_array<const M: usize>(
+        &mut self,
+        arr: Array<T, M>,
+    ) -> Result<(), CapacityError<Array<T, M>>> {
+        let tail = self.queue.tail.load(Ordering::Relaxed);
+        let len = distance::<L>(self.queue.head.load(Ordering::Acquire), tail);
+        if arr.len() > L - len {
+            return Err(CapacityError::new(arr, len, L));
+        }
+        let mut pos = tail;
+        for element in arr {
+            unsafe { (*self.queue.slot(pos)).write(element) };
+            pos = advance::<L>(pos, 1);
+        }
+        self.queue.tail.store(pos, Ordering::Release);
+        Ok(())
+    }
+}
+
+impl<T, const L: usize> Debug for Producer<'_, T, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Producer")
+            .field("free", &self.free())
+            .finish()
+    }
+}
+
+/// The popping half of a [`Queue`].
+///
+/// Created by [`Queue::split`].
+pub struct Consumer<'a, T, const L: usize> {
+    queue: &'a Queue<T, L>,
+}
+
+impl<T, const L: usize> Consumer<'_, T, L> {
+    /// Returns the number of elements ready to pop. May grow concurrently as the producer
+    /// pushes.
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Removes and returns the oldest element, or `None` if the queue is empty.
+    pub fn try_pop(&mut self) -> Option<T> {
+        let head = self.queue.head.load(Ordering::Relaxed);
+        if head == self.queue.tail.load(Ordering::Acquire) {
+            return None;
+        }
+        let element = unsafe { (*self.queue.slot(head)).assume_init_read() };
+        self.queue
+            .head
+            .store(advance::<L>(head, 1), Ordering::Release);
+        Some(element)
+    }
+
+    /// Returns the oldest element without removing it, or `None` if the queue is empty.
+    ///
+    /// Takes `&mut self` so the element cannot be reached through a consumer shared between
+    /// threads, which would be unsound for elements that are not [`Sync`]:
+    ///
+    /// ```compile_fail
+    /// use arrayy::spsc::Queue;
+    /// use std::{cell::Cell, thread};
+    ///
+    /// let mut queue = Queue::<Cell<u32>, 4>::new();
+    /// let (mut producer, consumer) = queue.split();
+    /// producer.try_push(Cell::new(0)).unwrap();
+    /// let consumer = &consumer;
+    /// thread::scope(|s| {
+    ///     s.spawn(|| consumer.peek().unwrap().set(1));
+    ///     consumer.peek().unwrap().set(2);
+    /// });
+    /// ```
+    pub fn peek(&mut self) -> Option<&T> {
+        let head = self.queue.head.load(Ordering::Relaxed);
+        if head == self.queue.tail.load(Ordering::Acquire) {
+            return None;
+        }
+        Some(unsafe { (*self.queue.slot(head)).assume_init_ref() })
+    }
+
+    /// Pops up to `M` elements into a new array, oldest first.
+    pub fn pop_array<const M: usize>(&mut self) -> Array<T, M> {
+        let mut arr = Array::default();
+        self.pop_into(&mut arr);
+        arr
+    }
+
+    /// Pops as many elements as are ready and fit into `arr`, appending them oldest first.
+    /// Returns the number of elements popped.
+    pub fn pop_into<const M: usize>(&mut self, arr: &mut Array<T, M>) -> usize {
+        let head = self.queue.head.load(Ordering::Relaxed);
+        let ready = distance::<L>(head, self.queue.tail.load(Ordering::Acquire));
+        let n = ready.min(M - arr.len());
+        let mut pos = head;
+        for _ in 0..n {
+            unsafe { arr.push_unchecked((*self.queue.slot(pos)).assume_init_read()) };
+            pos = advance::<L>(pos, 1);
+        }
+        self.queue.head.store(pos, Ordering::Release);
+        n
+    }
+}
+
+impl<T, const L: usize> Debug for Consumer<'_, T, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Consumer")
+            .field("len", &self.len())
+            .finish()
+    }
+}
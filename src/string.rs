@@ -0,0 +1,357 @@
+use core::{
+    borrow::Borrow,
+    fmt::{self, Debug, Display},
+    hash::{Hash, Hasher},
+    ops::{Deref, DerefMut},
+    str::{self, FromStr, Utf8Error},
+};
+
+use crate::{Array, CapacityError};
+
+/// Fixed-capacity UTF-8 string storing up to `L` bytes inline in an [`Array<u8, L>`].
+///
+/// # Examples
+///
+/// ```
+/// use arrayy::ArrayString;
+/// let mut s = ArrayString::<16>::new();
+/// s.push_str("hello");
+/// s.push(' ');
+/// s.push_str("world");
+/// assert_eq!(s, "hello world");
+/// assert_eq!(s.len(), 11);
+/// assert_eq!(s.capacity(), 16);
+/// ```
+#[derive(Clone, Default)]
+pub struct ArrayString<const L: usize> {
+    vec: Array<u8, L>,
+}
+
+impl<const L: usize> ArrayString<L> {
+    /// Creates an empty string.
+    pub fn new() -> Self {
+        Self {
+            vec: Array::default(),
+        }
+    }
+
+    /// Creates a string from a `&str`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `s` exceeds the capacity.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::ArrayString;
+    /// let s = ArrayString::<8>::from("abc");
+    /// assert_eq!(s.as_str(), "abc");
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn from(s: &str) -> Self {
+        let mut string = Self::new();
+        string.push_str(s);
+        string
+    }
+
+    /// Creates a string from a `&str`, or returns it in a [`CapacityError`] if it does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::ArrayString;
+    /// assert!(ArrayString::<3>::try_from_str("abc").is_ok());
+    /// assert_eq!(ArrayString::<2>::try_from_str("abc").unwrap_err().element(), "abc");
+    /// ```
+    pub fn try_from_str(s: &str) -> Result<Self, CapacityError<&str>> {
+        let mut string = Self::new();
+        string.try_push_str(s)?;
+        Ok(string)
+    }
+
+    /// Converts an array of bytes to a string, checking that it is valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::{array, ArrayString};
+    /// let s = ArrayString::from_utf8(array!(b'h', b'i'; 4)).unwrap();
+    /// assert_eq!(s, "hi");
+    /// assert!(ArrayString::from_utf8(array!(0xff_u8)).is_err());
+    /// ```
+    pub fn from_utf8(vec: Array<u8, L>) -> Result<Self, Utf8Error> {
+        str::from_utf8(vec.as_slice())?;
+        Ok(Self { vec })
+    }
+
+    /// Converts an array of bytes to a string without checking that it is valid UTF-8.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that the bytes are valid UTF-8.
+    pub unsafe fn from_utf8_unchecked(vec: Array<u8, L>) -> Self {
+        Self { vec }
+    }
+
+    /// Converts the string into its underlying byte array.
+    pub fn into_bytes(self) -> Array<u8, L> {
+        self.vec
+    }
+
+    /// Returns the length of the string in bytes.
+    pub const fn len(&self) -> usize {
+        self.vec.len()
+    }
+
+    /// Returns the capacity of the string in bytes.
+    pub const fn capacity(&self) -> usize {
+        L
+    }
+
+    /// Returns `true` if the string is empty.
+    pub const fn is_empty(&self) -> bool {
+        self.vec.is_empty()
+    }
+
+    pub fn as_str(&self) -> &str {
+        unsafe { str::from_utf8_unchecked(self.vec.as_slice()) }
+    }
+
+    pub fn as_mut_str(&mut self) -> &mut str {
+        unsafe { str::from_utf8_unchecked_mut(self.vec.as_mut_slice()) }
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        self.vec.as_slice()
+    }
+
+    /// Appends a string slice to the end of the string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting length exceeds the capacity.
+    pub fn push_str(&mut self, s: &str) {
+        self.vec.append_slice(s.as_bytes());
+    }
+
+    /// Appends a string slice to the end of the string, or returns it in a [`CapacityError`] if it
+    /// does not fit. Nothing is appended on error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::ArrayString;
+    /// let mut s = ArrayString::<4>::from("ab");
+    /// assert!(s.try_push_str("cd").is_ok());
+    /// assert_eq!(s.try_push_str("e").unwrap_err().element(), "e");
+    /// assert_eq!(s, "abcd");
+    /// ```
+    pub fn try_push_str<'a>(&mut self, s: &'a str) -> Result<(), CapacityError<&'a str>> {
+        match self.vec.try_append_slice(s.as_bytes()) {
+            Ok(()) => Ok(()),
+            Err(err) => Err(CapacityError::new(s, err.len(), err.capacity())),
+        }
+    }
+
+    /// Appends a character to the end of the string.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the resulting length exceeds the capacity.
+    pub fn push(&mut self, ch: char) {
+        self.push_str(ch.encode_utf8(&mut [0; 4]));
+    }
+
+    /// Appends a character to the end of the string, or returns it in a [`CapacityError`] if it
+    /// does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::ArrayString;
+    /// let mut s = ArrayString::<2>::from("a");
+    /// assert_eq!(s.try_push('é').unwrap_err().element(), 'é');
+    /// assert!(s.try_push('b').is_ok());
+    /// ```
+    pub fn try_push(&mut self, ch: char) -> Result<(), CapacityError<char>> {
+        match self.try_push_str(ch.encode_utf8(&mut [0; 4])) {
+            Ok(()) => Ok(()),
+            Err(err) => Err(CapacityError::new(ch, err.len(), err.capacity())),
+        }
+    }
+
+    /// Removes the last character from the string and returns it, or `None` if it is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::ArrayString;
+    /// let mut s = ArrayString::<8>::from("añ");
+    /// assert_eq!(s.pop(), Some('ñ'));
+    /// assert_eq!(s, "a");
+    /// ```
+    pub fn pop(&mut self) -> Option<char> {
+        let ch = self.chars().next_back()?;
+        let new_len = self.len() - ch.len_utf8();
+        self.vec.truncate(new_len);
+        Some(ch)
+    }
+
+    /// Shortens the string to `new_len` bytes. Has no effect if `new_len` is greater than the
+    /// current length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `new_len` does not lie on a char boundary.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use arrayy::ArrayString;
+    /// let mut s = ArrayString::<8>::from("hello");
+    /// s.truncate(2);
+    /// assert_eq!(s, "he");
+    /// ```
+    pub fn truncate(&mut self, new_len: usize) {
+        if new_len <= self.len() {
+            if !self.is_char_boundary(new_len) {
+                panic!("truncate index ({}) is not a char boundary", new_len);
+            }
+            self.vec.truncate(new_len);
+        }
+    }
+
+    /// Clears the string, removing all characters.
+    pub fn clear(&mut self) {
+        self.vec.clear();
+    }
+}
+
+impl<const L: usize> Deref for ArrayString<L> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<const L: usize> DerefMut for ArrayString<L> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.as_mut_str()
+    }
+}
+
+impl<const L: usize> AsRef<str> for ArrayString<L> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const L: usize> AsRef<[u8]> for ArrayString<L> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<const L: usize> Borrow<str> for ArrayString<L> {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const L: usize> fmt::Write for ArrayString<L> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.try_push_str(s).map_err(|_| fmt::Error)
+    }
+
+    fn write_char(&mut self, c: char) -> fmt::Result {
+        self.try_push(c).map_err(|_| fmt::Error)
+    }
+}
+
+impl<const L: usize> Display for ArrayString<L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<const L: usize> Debug for ArrayString<L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const L: usize> FromStr for ArrayString<L> {
+    type Err = CapacityError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from_str(s).map_err(CapacityError::simplify)
+    }
+}
+
+impl<const L: usize> Hash for ArrayString<L> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state)
+    }
+}
+
+impl<const L: usize, const M: usize> PartialEq<ArrayString<M>> for ArrayString<L> {
+    fn eq(&self, other: &ArrayString<M>) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const L: usize> Eq for ArrayString<L> {}
+
+impl<const L: usize> PartialEq<str> for ArrayString<L> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const L: usize> PartialEq<&str> for ArrayString<L> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const L: usize> PartialEq<ArrayString<L>> for str {
+    fn eq(&self, other: &ArrayString<L>) -> bool {
+        self == other.as_str()
+    }
+}
+
+impl<const L: usize> PartialEq<ArrayString<L>> for &str {
+    fn eq(&self, other: &ArrayString<L>) -> bool {
+        *self == other.as_str()
+    }
+}
+
+impl<const L: usize, const M: usize> PartialOrd<ArrayString<M>> for ArrayString<L> {
+    fn partial_cmp(&self, other: &ArrayString<M>) -> Option<core::cmp::Ordering> {
+        self.as_str().partial_cmp(other.as_str())
+    }
+}
+
+impl<const L: usize> Ord for ArrayString<L> {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+#[macro_export]
+macro_rules! array_str {
+    () => {
+        $crate::ArrayString::default()
+    };
+    (;$cap:expr) => {
+        $crate::ArrayString::<$cap>::new()
+    };
+    ($s:expr ;$cap:expr) => {
+        $crate::ArrayString::<$cap>::from($s)
+    };
+    ($s:expr) => {
+        $crate::ArrayString::<{ $s.len() }>::from($s)
+    };
+}
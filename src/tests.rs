@@ -1,6 +1,14 @@
 #![cfg(test)]
 
 use super::*;
+use std::{
+    boxed::Box,
+    format,
+    rc::Rc,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
 #[test]
 fn test_general() {
     let mut arr = Array::<usize, 5>::default();
@@ -29,19 +37,17 @@ fn test_general() {
     let arr2: Array<i32, 5> = Array::from(&[4, 5]);
     assert_eq!(arr2.len, 2);
 
-    arr1.append(&arr2);
+    arr1.append(arr2.clone());
     assert_eq!(arr1.len, 5);
     assert_eq!(arr2.len, 2);
-    assert_eq!(arr1.data, [1, 2, 3, 4, 5]);
+    assert_eq!(arr1.as_slice(), [1, 2, 3, 4, 5]);
 
     let arr: Array<u8, 10> = Array::from(&[1, 2, 3]);
-    unsafe {
-        let mut unsafe_iter = arr.into_iter();
-        assert_eq!(Some(1), unsafe_iter.next());
-        assert_eq!(Some(2), unsafe_iter.next());
-        assert_eq!(Some(3), unsafe_iter.next());
-        assert_eq!(None, unsafe_iter.next());
-    }
+    let mut iter = arr.into_iter();
+    assert_eq!(Some(1), iter.next());
+    assert_eq!(Some(2), iter.next());
+    assert_eq!(Some(3), iter.next());
+    assert_eq!(None, iter.next());
 }
 
 #[test]
@@ -80,14 +86,14 @@ fn test_set_len() {
 fn test_buf() {
     let arr = array!(1, 2, 3);
     let buf = arr.buf();
-    assert_eq!(buf, &[1, 2, 3]);
+    assert_eq!(unsafe { buf.map(|x| x.assume_init()) }, [1, 2, 3]);
 }
 
 #[test]
 fn test_buf_mut() {
     let mut arr = array!(1, 2, 3);
     let buf_mut = arr.buf_mut();
-    buf_mut[0] = 4;
+    buf_mut[0].write(4);
     assert_eq!(arr.get(0), Some(&4));
 }
 
@@ -186,7 +192,7 @@ fn test_iter_mut() {
 fn test_append() {
     let mut arr1 = array!(1, 2, 3; 5);
     let arr2 = array!(4, 5);
-    arr1.append(&arr2);
+    arr1.append(arr2);
     assert_eq!(arr1, array!(1, 2, 3, 4, 5));
 }
 
@@ -233,6 +239,7 @@ fn test_as_mut_slice() {
     assert_eq!(arr, array!(4, 2, 3));
 }
 
+#[cfg(feature = "alloc")]
 #[test]
 fn test_as_vec() {
     let arr = array!(1, 2, 3);
@@ -294,13 +301,40 @@ fn test_as_ptr_range() {
 #[test]
 fn test_into_iter() {
     let arr = array!(1, 2, 3);
-    unsafe {
-        let mut iter = arr.into_iter();
-        assert_eq!(iter.next(), Some(1));
-        assert_eq!(iter.next(), Some(2));
-        assert_eq!(iter.next(), Some(3));
-        assert_eq!(iter.next(), None);
+    let mut iter = arr.into_iter();
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.next(), Some(1));
+    assert_eq!(iter.next_back(), Some(3));
+    assert_eq!(iter.as_slice(), &[2]);
+    let mut clone = iter.clone();
+    assert_eq!(iter.next(), Some(2));
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(clone.next(), Some(2));
+
+    let mut sum = 0;
+    for x in array!(1, 2, 3; 5) {
+        sum += x;
+    }
+    assert_eq!(sum, 6);
+
+    let mut arr = array!(1, 2, 3);
+    for x in &mut arr {
+        *x += 1;
     }
+    let refs: Vec<&i32> = (&arr).into_iter().collect();
+    assert_eq!(refs, [&2, &3, &4]);
+}
+
+#[test]
+fn test_into_iter_drop() {
+    let rc = Rc::new(());
+    let arr = Array::<_, 4>::from(&[rc.clone(), rc.clone(), rc.clone()]);
+    let mut iter = arr.into_iter();
+    drop(iter.next());
+    assert_eq!(Rc::strong_count(&rc), 3);
+    drop(iter);
+    assert_eq!(Rc::strong_count(&rc), 1);
 }
 
 #[test]
@@ -357,3 +391,1492 @@ fn test_macro() {
     assert_eq!(arr.len(), 3);
     assert_eq!(arr.capacity(), 10);
 }
+
+#[test]
+fn test_non_copy() {
+    let mut arr = Array::<String, 4>::default();
+    arr.push(String::from("a"));
+    arr.push(String::from("b"));
+    arr.insert(0, String::from("c"));
+    let (a, b, c) = (String::from("a"), String::from("b"), String::from("c"));
+    assert_eq!(arr, array!(c, a, b));
+    assert_eq!(arr.remove(1), "a");
+    assert_eq!(arr.pop(), Some(String::from("b")));
+    let arr = arr.map(|s| s.len());
+    assert_eq!(arr, array!(1));
+
+    let arr = Array::<_, 4>::from(&[Box::new(1), Box::new(2), Box::new(3)]);
+    let arr = arr.filter(|x| **x != 2);
+    assert_eq!(arr.as_slice(), [Box::new(1), Box::new(3)]);
+    let mut other = arr.clone();
+    other.append(arr);
+    assert_eq!(other.len(), 4);
+    assert_eq!(*other[3], 3);
+}
+
+#[test]
+fn test_drop() {
+    let rc = Rc::new(());
+    let mut arr = Array::<Rc<()>, 8>::default();
+    for _ in 0..6 {
+        arr.push(rc.clone());
+    }
+    assert_eq!(Rc::strong_count(&rc), 7);
+    drop(arr.pop());
+    assert_eq!(Rc::strong_count(&rc), 6);
+    arr.truncate(3);
+    assert_eq!(Rc::strong_count(&rc), 4);
+    let arr = arr.filter(|_| false);
+    assert_eq!(Rc::strong_count(&rc), 1);
+    let mut arr = arr.map(|x| x.clone());
+    arr.push(rc.clone());
+    arr.push(rc.clone());
+    assert_eq!(Rc::strong_count(&rc), 3);
+    arr.clear();
+    assert_eq!(Rc::strong_count(&rc), 1);
+    arr.push(rc.clone());
+    drop(arr);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_try_push() {
+    let mut arr = array!(1, 2; 3);
+    assert_eq!(arr.try_push(3), Ok(()));
+    let err = arr.try_push(4).unwrap_err();
+    assert_eq!((err.len(), err.capacity()), (3, 3));
+    assert_eq!(err.element(), 4);
+    assert_eq!(arr, array!(1, 2, 3));
+}
+
+#[test]
+fn test_try_insert() {
+    let mut arr = array!(1, 2; 3);
+    assert_eq!(arr.try_insert(1, 0), Ok(()));
+    assert_eq!(arr.try_insert(0, 5).unwrap_err().element(), 5);
+    assert_eq!(arr, array!(1, 0, 2));
+}
+
+#[test]
+fn test_try_append() {
+    let mut arr1 = array!(1, 2, 3; 5);
+    let arr2 = array!(4, 5);
+    assert_eq!(arr1.try_append(arr2.clone()), Ok(()));
+    let err = arr1.try_append(arr2.clone()).unwrap_err();
+    assert_eq!(err.element(), arr2);
+    assert_eq!(arr1, array!(1, 2, 3, 4, 5));
+}
+
+#[test]
+fn test_try_append_slice() {
+    let mut arr = array!(1,; 3);
+    assert_eq!(arr.try_append_slice(&[2]), Ok(()));
+    let err = arr.try_append_slice(&[3, 4]).unwrap_err();
+    assert_eq!((err.len(), err.capacity()), (2, 3));
+    assert_eq!(err.element(), &[3, 4]);
+    assert_eq!(arr, array!(1, 2));
+}
+
+#[test]
+fn test_try_from_slice() {
+    let arr = Array::<i32, 3>::try_from_slice(&[1, 2]).unwrap();
+    assert_eq!(arr, array!(1, 2));
+    let err = Array::<i32, 1>::try_from_slice(&[1, 2]).unwrap_err();
+    assert_eq!(err.simplify(), CapacityError::new((), 0, 1));
+    assert_eq!(
+        err.to_string(),
+        "insufficient capacity: len (0), capacity (1)"
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_push_full() {
+    let mut arr = array!(1, 2, 3);
+    arr.push(4);
+}
+
+#[test]
+fn test_from_iter() {
+    let arr: Array<i32, 5> = (1..4).collect();
+    assert_eq!(arr, array!(1, 2, 3));
+    let arr: Array<String, 3> = ["a", "b"].iter().map(|s| s.to_string()).collect();
+    assert_eq!(arr.as_slice(), ["a", "b"]);
+}
+
+#[test]
+#[should_panic]
+fn test_from_iter_overflow() {
+    let _: Array<i32, 2> = (1..4).collect();
+}
+
+#[test]
+fn test_extend() {
+    let mut arr = array!(1,; 5);
+    arr.extend(vec![2, 3]);
+    arr.extend(&[4, 5]);
+    assert_eq!(arr, array!(1, 2, 3, 4, 5));
+}
+
+#[test]
+fn test_try_extend() {
+    let mut arr = array!(1,; 3);
+    assert_eq!(arr.try_extend(2..3), Ok(()));
+    let mut iter = 3..10;
+    let err = arr.try_extend(iter.by_ref()).unwrap_err();
+    assert_eq!(err.element(), 4);
+    assert_eq!(arr, array!(1, 2, 3));
+    assert_eq!(iter.next(), Some(5));
+
+    assert_eq!(Array::<i32, 3>::try_from_iter(1..4), Ok(array!(1, 2, 3)));
+    assert!(Array::<i32, 2>::try_from_iter(1..4).is_err());
+}
+
+#[test]
+fn test_truncated() {
+    let arr = Array::<i32, 3>::from_iter_truncated(1..10);
+    assert_eq!(arr, array!(1, 2, 3));
+    let mut arr = array!(1,; 2);
+    arr.extend_truncated(5..);
+    assert_eq!(arr, array!(1, 5));
+}
+
+#[test]
+fn test_retain() {
+    let mut arr = array!(1, 2, 3, 4, 5);
+    arr.retain(|&x| x != 3);
+    assert_eq!(arr, array!(1, 2, 4, 5));
+    arr.retain_mut(|x| {
+        *x += 1;
+        *x % 2 == 0
+    });
+    assert_eq!(arr, array!(2, 6));
+}
+
+#[test]
+fn test_dedup() {
+    let mut arr = array!(1, 1, 2, 2, 2, 3, 1, 1);
+    arr.dedup();
+    assert_eq!(arr, array!(1, 2, 3, 1));
+    let mut arr = array!(1, 2, 11, 12, 21);
+    arr.dedup_by_key(|x| *x / 10);
+    assert_eq!(arr, array!(1, 11, 21));
+    let mut arr = Array::<String, 4>::from(&["a".into(), "A".into(), "b".into()]);
+    arr.dedup_by(|a, b| a.eq_ignore_ascii_case(b));
+    assert_eq!(arr.as_slice(), ["a", "b"]);
+}
+
+#[test]
+fn test_swap_remove() {
+    let mut arr = array!(1, 2, 3);
+    assert_eq!(arr.swap_remove(0), 1);
+    assert_eq!(arr, array!(3, 2));
+    assert_eq!(arr.swap_remove(1), 2);
+    assert_eq!(arr, array!(3));
+}
+
+#[test]
+fn test_split_off() {
+    let mut arr = array!(1, 2, 3, 4);
+    let tail = arr.split_off(2);
+    assert_eq!(arr, array!(1, 2));
+    assert_eq!(tail, array!(3, 4));
+    assert_eq!(tail.capacity(), 4);
+    assert!(arr.split_off(2).is_empty());
+}
+
+#[test]
+fn test_resize() {
+    let mut arr = array!(1,; 4);
+    arr.resize(3, 7);
+    assert_eq!(arr, array!(1, 7, 7));
+    arr.resize(2, 0);
+    assert_eq!(arr, array!(1, 7));
+    arr.resize_with(4, Default::default);
+    assert_eq!(arr, array!(1, 7, 0, 0));
+}
+
+#[test]
+fn test_drain() {
+    let mut arr = array!(1, 2, 3, 4, 5);
+    let mut drain = arr.drain(1..4);
+    assert_eq!(drain.len(), 3);
+    assert_eq!(drain.next(), Some(2));
+    assert_eq!(drain.next_back(), Some(4));
+    drop(drain);
+    assert_eq!(arr, array!(1, 5));
+    let all: Vec<i32> = arr.drain(..).collect();
+    assert_eq!(all, vec![1, 5]);
+    assert!(arr.is_empty());
+}
+
+#[test]
+fn test_drain_drop() {
+    let rc = Rc::new(());
+    let mut arr = Array::<_, 4>::from(&[rc.clone(), rc.clone(), rc.clone(), rc.clone()]);
+    arr.drain(1..=2);
+    assert_eq!(arr.len(), 2);
+    assert_eq!(Rc::strong_count(&rc), 3);
+}
+
+#[test]
+#[should_panic]
+fn test_drain_out_of_bounds() {
+    let mut arr = array!(1, 2, 3);
+    arr.drain(2..4);
+}
+
+#[test]
+fn test_splice() {
+    let mut arr = array!(1, 2, 3; 6);
+    let removed = arr.splice(1..2, [7, 8, 9]);
+    assert_eq!(removed, array!(2));
+    assert_eq!(arr, array!(1, 7, 8, 9, 3));
+    let removed = arr.splice(..4, []);
+    assert_eq!(removed, array!(1, 7, 8, 9));
+    assert_eq!(arr, array!(3));
+    let err = arr.try_splice(.., [0; 7]).unwrap_err();
+    assert_eq!(err.element().len(), 7);
+    assert_eq!(arr, array!(3));
+}
+
+#[test]
+fn test_extract_if() {
+    let mut arr = array!(1, 2, 3, 4, 5, 6);
+    let evens: Vec<i32> = arr.extract_if(.., |x| *x % 2 == 0).collect();
+    assert_eq!(evens, vec![2, 4, 6]);
+    assert_eq!(arr, array!(1, 3, 5));
+
+    let mut arr = array!(1, 2, 3, 4, 5, 6);
+    let mut iter = arr.extract_if(1..5, |x| *x % 2 == 0);
+    assert_eq!(iter.next(), Some(2));
+    drop(iter);
+    assert_eq!(arr, array!(1, 3, 4, 5, 6));
+}
+
+#[test]
+fn test_array_string() {
+    let mut s = ArrayString::<8>::new();
+    s.push_str("ab");
+    s.push('ñ');
+    assert_eq!(s, "abñ");
+    assert_eq!(s.len(), 4);
+    assert_eq!(s.capacity(), 8);
+    assert_eq!(s.pop(), Some('ñ'));
+    assert_eq!(s.pop(), Some('b'));
+    assert_eq!(&s[..], "a");
+    s.clear();
+    assert!(s.is_empty());
+    assert_eq!(s.pop(), None);
+}
+
+#[test]
+fn test_array_string_capacity() {
+    let mut s = ArrayString::<3>::from("ab");
+    let err = s.try_push('ñ').unwrap_err();
+    assert_eq!((err.element(), err.len(), err.capacity()), ('ñ', 2, 3));
+    assert_eq!(s.try_push_str("cd").unwrap_err().element(), "cd");
+    assert_eq!(s, "ab");
+    assert!(s.try_push('c').is_ok());
+    assert!("abcd".parse::<ArrayString<3>>().is_err());
+    assert_eq!("abc".parse::<ArrayString<3>>().unwrap(), "abc");
+}
+
+#[test]
+#[should_panic]
+fn test_array_string_truncate_boundary() {
+    let mut s = ArrayString::<4>::from("ñ");
+    s.truncate(1);
+}
+
+#[test]
+fn test_array_string_traits() {
+    use std::collections::HashSet;
+    use std::fmt::Write;
+
+    let mut s = ArrayString::<16>::new();
+    let x = 'x';
+    write!(s, "{}-{}", 1, x).unwrap();
+    assert_eq!(s.to_string(), "1-x");
+    assert_eq!(format!("{:?}", s), "\"1-x\"");
+    assert!(s.write_str("a very long string").is_err());
+    assert!(ArrayString::<4>::from("abc") < ArrayString::<8>::from("abd"));
+    let set: HashSet<ArrayString<4>> = ["a", "b"].iter().map(|s| s.parse().unwrap()).collect();
+    assert!(set.contains("a"));
+    s.truncate(1);
+    s.make_ascii_uppercase();
+    assert_eq!(s, "1");
+}
+
+#[test]
+fn test_array_str_macro() {
+    let s = array_str!("hello");
+    assert_eq!(s.capacity(), 5);
+    assert_eq!(s, "hello");
+    let s = array_str!("hi"; 10);
+    assert_eq!((s.len(), s.capacity()), (2, 10));
+    let s = array_str!(; 4);
+    assert!(s.is_empty());
+    let s: ArrayString<4> = array_str!();
+    assert_eq!(s.capacity(), 4);
+}
+
+#[test]
+fn test_deque() {
+    let mut dq = ArrayDeque::<i32, 4>::new();
+    assert!(dq.is_empty());
+    dq.push_back(1);
+    dq.push_back(2);
+    dq.push_front(0);
+    dq.push_front(-1);
+    assert!(dq.is_full());
+    assert_eq!(dq.try_push_back(3).unwrap_err().element(), 3);
+    assert_eq!(dq.try_push_front(3).unwrap_err().element(), 3);
+    assert_eq!(dq.as_slices(), (&[-1, 0][..], &[1, 2][..]));
+    assert_eq!((dq[0], dq[3]), (-1, 2));
+    assert_eq!(dq.front(), Some(&-1));
+    assert_eq!(dq.back(), Some(&2));
+    dq[1] = 10;
+    assert_eq!(dq.pop_front(), Some(-1));
+    assert_eq!(dq.pop_back(), Some(2));
+    assert_eq!(dq.iter().copied().collect::<Vec<_>>(), vec![10, 1]);
+    assert_eq!(dq.pop_back(), Some(1));
+    assert_eq!(dq.pop_back(), Some(10));
+    assert_eq!(dq.pop_front(), None);
+    assert_eq!(dq.get(0), None);
+}
+
+#[test]
+fn test_deque_wrap() {
+    let mut dq = ArrayDeque::<i32, 3>::new();
+    for i in 0..10 {
+        dq.push_back(i);
+        if dq.len() == 3 {
+            assert_eq!(dq.pop_front(), Some(i - 2));
+        }
+    }
+    assert_eq!(dq, [8, 9].into_iter().collect::<ArrayDeque<i32, 3>>());
+    dq.push_back(10);
+    assert_eq!(dq.make_contiguous(), &[8, 9, 10]);
+    for x in dq.iter_mut() {
+        *x *= 2;
+    }
+    assert_eq!(dq.into_iter().rev().collect::<Vec<_>>(), vec![20, 18, 16]);
+
+    // iterating by mutable reference crosses the wrap point too
+    let mut dq: ArrayDeque<i32, 3> = [1, 2].into_iter().collect();
+    dq.pop_front();
+    dq.push_back(3);
+    dq.push_back(4);
+    assert_eq!(dq.as_slices(), (&[2, 3][..], &[4][..]));
+    for x in &mut dq {
+        *x += 10;
+    }
+    assert_eq!(
+        (&dq).into_iter().copied().collect::<Vec<_>>(),
+        vec![12, 13, 14]
+    );
+}
+
+#[test]
+fn test_deque_overwrite() {
+    let mut dq = ArrayDeque::<i32, 3>::new_overwrite();
+    dq.extend(0..5);
+    assert_eq!(dq.iter().copied().collect::<Vec<_>>(), vec![2, 3, 4]);
+    dq.push_front(1);
+    assert_eq!(dq.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+    dq.set_overwrite(false);
+    assert!(dq.try_push_back(0).is_err());
+    assert_eq!(dq.push_back_overwrite(4), Some(1));
+    assert_eq!(dq.push_front_overwrite(1), Some(4));
+
+    let mut empty = ArrayDeque::<i32, 0>::new_overwrite();
+    empty.push_back(1);
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_deque_drop() {
+    let rc = Rc::new(());
+    let mut dq = ArrayDeque::<_, 3>::new_overwrite();
+    for _ in 0..5 {
+        dq.push_front(rc.clone());
+    }
+    assert_eq!(Rc::strong_count(&rc), 4);
+    let clone = dq.clone();
+    assert_eq!(Rc::strong_count(&rc), 7);
+    drop(dq);
+    let mut iter = clone.into_iter();
+    iter.next();
+    assert_eq!(Rc::strong_count(&rc), 3);
+    drop(iter);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_concat() {
+    let arr: Array<i32, 6> = array!(1, 2; 3).concat(array!(3, 4; 3));
+    assert_eq!(arr, array!(1, 2, 3, 4));
+    assert_eq!(arr.capacity(), 6);
+    let (a, b) = (String::from("a"), String::from("b"));
+    let arr: Array<String, 2> = array!(a).concat(array!(b));
+    assert_eq!(arr.as_slice(), ["a", "b"]);
+}
+
+#[test]
+fn test_split_at_const() {
+    let (head, tail): (Array<i32, 1>, Array<i32, 4>) = array!(1, 2, 3; 5).split_at_const();
+    assert_eq!(head, array!(1));
+    assert_eq!(tail, array!(2, 3));
+}
+
+#[test]
+#[should_panic]
+fn test_split_at_const_len() {
+    let _: (Array<i32, 4>, Array<i32, 1>) = array!(1, 2, 3; 5).split_at_const();
+}
+
+#[test]
+fn test_resize_capacity() {
+    let arr: Array<i32, 10> = array!(1, 2, 3).resize_capacity();
+    assert_eq!((arr.len(), arr.capacity()), (3, 10));
+    let arr: Array<i32, 3> = arr.try_resize_capacity().unwrap();
+    assert_eq!(arr, array!(1, 2, 3));
+    let err = arr.try_resize_capacity::<2>().unwrap_err();
+    assert_eq!((err.len(), err.capacity()), (3, 2));
+    assert_eq!(err.element(), array!(1, 2, 3));
+}
+
+#[test]
+fn test_from_array() {
+    let arr: Array<i32, 5> = [1, 2, 3].into();
+    assert_eq!(arr, array!(1, 2, 3));
+    let arr: Array<String, 1> = From::from([String::from("a")]);
+    assert_eq!(arr.as_slice(), ["a"]);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde() {
+    let arr = array!(1, 2, 3; 5);
+    let json = serde_json::to_string(&arr).unwrap();
+    assert_eq!(json, "[1,2,3]");
+    let de: Array<i32, 5> = serde_json::from_str(&json).unwrap();
+    assert_eq!(de, arr);
+    let de: Array<i32, 3> = serde_json::from_str(&json).unwrap();
+    assert_eq!(de, arr);
+    let err = serde_json::from_str::<Array<i32, 2>>(&json).unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 3, expected a sequence of at most 2 elements"));
+    let err = serde_json::from_str::<Array<i32, 2>>("[1, 2, 3, [4, 5], 6]").unwrap_err();
+    assert!(err
+        .to_string()
+        .starts_with("invalid length 5, expected a sequence of at most 2 elements"));
+
+    let (a, b) = (String::from("a"), String::from("b"));
+    let arr = array!(a, b; 4);
+    let de: Array<String, 4> = serde_json::from_str(&serde_json::to_string(&arr).unwrap()).unwrap();
+    assert_eq!(de, arr);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_array_string() {
+    let s = array_str!("hello"; 8);
+    let json = serde_json::to_string(&s).unwrap();
+    assert_eq!(json, "\"hello\"");
+    let de: ArrayString<5> = serde_json::from_str(&json).unwrap();
+    assert_eq!(de, s);
+    let err = serde_json::from_str::<ArrayString<4>>(&json).unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("expected a string of at most 4 bytes"));
+}
+
+#[test]
+fn test_cmp() {
+    let arr = array!(1, 2, 3; 5);
+    assert_eq!(arr, [1, 2, 3]);
+    assert_eq!([1, 2, 3], arr);
+    assert_eq!(arr, [1, 2, 3][..]);
+    assert_eq!(arr, &[1, 2, 3][..]);
+    assert_eq!(&[1, 2, 3][..], arr);
+    assert_ne!(arr, [1, 2]);
+    assert!(array!(1, 2) < array!(1, 3; 4));
+    assert!(array!(1, 2; 4) > array!(1));
+    assert_eq!(
+        array!(2, 1).cmp(&array!(1, 2)),
+        core::cmp::Ordering::Greater
+    );
+    let mut sorted = [array!(3, 1; 2), array!(1,; 2), array!(2, 2; 2)];
+    sorted.sort();
+    assert_eq!(sorted, [array!(1,; 2), array!(2, 2; 2), array!(3, 1; 2)]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_cmp_vec() {
+    let arr = array!(1, 2, 3; 5);
+    assert_eq!(arr, vec![1, 2, 3]);
+    assert_eq!(vec![1, 2, 3], arr);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn test_hash() {
+    use std::collections::{BTreeMap, HashMap};
+
+    let mut map = HashMap::new();
+    map.insert(array!(1, 2; 4), "a");
+    map.insert(array!(3,; 4), "b");
+    assert_eq!(map.get(&[1, 2][..]), Some(&"a"));
+    assert_eq!(map.get(&array!(3,; 4)), Some(&"b"));
+    assert_eq!(map.get(&[1][..]), None);
+
+    let mut map = BTreeMap::new();
+    map.insert(array!(2,; 4), 2);
+    map.insert(array!(1, 5; 4), 1);
+    assert_eq!(map.get(&[2][..]), Some(&2));
+    assert_eq!(map.values().copied().collect::<Vec<_>>(), vec![1, 2]);
+}
+
+#[test]
+fn test_array_map() {
+    let mut map = ArrayMap::<String, i32, 3>::new();
+    assert_eq!(map.insert("b".into(), 2), Ok(None));
+    assert_eq!(map.insert("a".into(), 1), Ok(None));
+    assert_eq!(map.insert("c".into(), 3), Ok(None));
+    assert_eq!(map.insert("b".into(), 20), Ok(Some(2)));
+    let err = map.insert("d".into(), 4).unwrap_err();
+    assert_eq!(err.element(), ("d".into(), 4));
+    assert_eq!(map.len(), 3);
+    assert_eq!(map.get("b"), Some(&20));
+    assert_eq!(map["a"], 1);
+    assert!(map.contains_key("c"));
+    assert!(!map.contains_key("d"));
+    *map.get_mut("c").unwrap() += 1;
+    assert_eq!(
+        map.iter()
+            .map(|(k, v)| (k.as_str(), *v))
+            .collect::<Vec<_>>(),
+        vec![("a", 1), ("b", 20), ("c", 4)]
+    );
+    assert_eq!(map.remove("b"), Some(20));
+    assert_eq!(map.remove("b"), None);
+    assert_eq!(map.pop_first(), Some(("a".into(), 1)));
+    assert_eq!(map.pop_last(), Some(("c".into(), 4)));
+    assert!(map.is_empty());
+}
+
+#[test]
+fn test_array_map_entry() {
+    let mut map = ArrayMap::<char, u32, 5>::new();
+    for c in "abracadabra".chars() {
+        map.entry(c).and_modify(|n| *n += 1).or_insert(1);
+    }
+    assert_eq!(map.get(&'a'), Some(&5));
+    assert_eq!(map.get(&'r'), Some(&2));
+    *map.entry('d').or_default() += 10;
+    assert_eq!(map[&'d'], 11);
+    match map.entry('z') {
+        map::Entry::Vacant(e) => assert_eq!(e.try_insert(0).unwrap_err().element(), ('z', 0)),
+        map::Entry::Occupied(_) => unreachable!(),
+    }
+    match map.entry('b') {
+        map::Entry::Occupied(e) => assert_eq!(e.remove_entry(), ('b', 2)),
+        map::Entry::Vacant(_) => unreachable!(),
+    }
+    assert_eq!(map.len(), 4);
+}
+
+#[test]
+fn test_array_map_range() {
+    let map: ArrayMap<i32, i32, 8> = (0..8).rev().map(|x| (x * 2, x)).collect();
+    let keys = |r: &mut dyn Iterator<Item = (&i32, &i32)>| r.map(|(k, _)| *k).collect::<Vec<_>>();
+    assert_eq!(keys(&mut map.range(3..9)), vec![4, 6, 8]);
+    assert_eq!(keys(&mut map.range(4..=8)), vec![4, 6, 8]);
+    assert_eq!(keys(&mut map.range(..2)), vec![0]);
+    assert_eq!(keys(&mut map.range(13..)), vec![14]);
+    assert_eq!(keys(&mut map.range(20..)), Vec::<i32>::new());
+    assert_eq!(keys(&mut map.range(12..).rev()), vec![14, 12]);
+}
+
+#[test]
+fn test_array_map_iter() {
+    let mut map: ArrayMap<i32, i32, 4> = [(3, 30), (1, 10), (2, 20)].into_iter().collect();
+    for (_, v) in &mut map {
+        *v += 1;
+    }
+    map.retain(|k, _| *k != 2);
+    assert_eq!(map.keys().copied().collect::<Vec<_>>(), vec![1, 3]);
+    assert_eq!(map.values().copied().collect::<Vec<_>>(), vec![11, 31]);
+    assert_eq!(map.first_key_value(), Some((&1, &11)));
+    assert_eq!(map.last_key_value(), Some((&3, &31)));
+    assert_eq!(format!("{:?}", map), "{1: 11, 3: 31}");
+    assert_eq!(map.clone(), map);
+    assert_eq!(map.into_iter().collect::<Vec<_>>(), vec![(1, 11), (3, 31)]);
+}
+
+#[test]
+fn test_array_set() {
+    let mut set = ArraySet::<i32, 3>::new();
+    assert_eq!(set.insert(2), Ok(true));
+    assert_eq!(set.insert(1), Ok(true));
+    assert_eq!(set.insert(2), Ok(false));
+    assert_eq!(set.insert(3), Ok(true));
+    assert_eq!(set.insert(0).unwrap_err().element(), 0);
+    assert_eq!(set.as_slice(), &[1, 2, 3]);
+    assert!(set.contains(&3));
+    assert_eq!(set.get(&2), Some(&2));
+    assert!(set.remove(&2));
+    assert!(!set.remove(&2));
+    assert_eq!(set.take(&3), Some(3));
+    assert_eq!(format!("{:?}", set), "{1}");
+
+    let set: ArraySet<String, 4> = ["b", "a", "b"].iter().map(|s| s.to_string()).collect();
+    assert_eq!(set.len(), 2);
+    assert!(set.contains("a"));
+    assert_eq!(set.first().map(String::as_str), Some("a"));
+}
+
+#[test]
+fn test_array_set_algebra() {
+    let a: ArraySet<i32, 5> = [1, 2, 3, 4].into_iter().collect();
+    let b: ArraySet<i32, 3> = [3, 4, 5].into_iter().collect();
+    let u: ArraySet<i32, 5> = a.union(&b).unwrap();
+    assert_eq!(u.as_slice(), &[1, 2, 3, 4, 5]);
+    let i: ArraySet<i32, 2> = a.intersection(&b).unwrap();
+    assert_eq!(i.as_slice(), &[3, 4]);
+    let d: ArraySet<i32, 2> = a.difference(&b).unwrap();
+    assert_eq!(d.as_slice(), &[1, 2]);
+    let s: ArraySet<i32, 3> = a.symmetric_difference(&b).unwrap();
+    assert_eq!(s.as_slice(), &[1, 2, 5]);
+    let err = a.union::<3, 4>(&b).unwrap_err();
+    assert_eq!((err.element(), err.len(), err.capacity()), (5, 4, 4));
+    assert!(a.intersection::<3, 1>(&b).is_err());
+
+    assert!(i.is_subset(&a));
+    assert!(i.is_subset(&b));
+    assert!(!a.is_subset(&b));
+    assert!(u.is_superset(&a));
+    assert!(!i.is_superset(&u));
+    assert!(d.is_disjoint(&b));
+    assert!(!a.is_disjoint(&b));
+    assert!(ArraySet::<i32, 0>::new().is_subset(&d));
+}
+
+#[test]
+fn test_array_heap() {
+    let mut heap = ArrayHeap::<i32, 5>::new();
+    for x in [3, 1, 4, 1, 5] {
+        heap.push(x);
+    }
+    assert!(heap.is_full());
+    assert_eq!(heap.try_push(9).unwrap_err().element(), 9);
+    assert_eq!(heap.peek(), Some(&5));
+    let mut popped = Vec::new();
+    while let Some(x) = heap.pop() {
+        popped.push(x);
+    }
+    assert_eq!(popped, vec![5, 4, 3, 1, 1]);
+    assert_eq!(heap.pop(), None);
+    assert!(heap.peek_mut().is_none());
+}
+
+#[test]
+fn test_array_heap_peek_mut() {
+    let mut heap: ArrayHeap<i32, 4> = [1, 7, 3, 5].into_iter().collect();
+    *heap.peek_mut().unwrap() = 2;
+    assert_eq!(heap.peek(), Some(&5));
+    let top = heap.peek_mut().unwrap();
+    assert_eq!(heap::PeekMut::pop(top), 5);
+    assert_eq!(heap.into_sorted_array(), array!(1, 2, 3));
+}
+
+#[test]
+fn test_array_heap_top_k() {
+    use core::cmp::Reverse;
+
+    let mut top = ArrayHeap::<Reverse<i32>, 3>::new();
+    let mut evicted = Vec::new();
+    for x in [4, 9, 1, 7, 3, 8] {
+        if let Some(Reverse(x)) = top.push_top_k(Reverse(x)) {
+            evicted.push(x);
+        }
+    }
+    assert_eq!(evicted, vec![1, 3, 4]);
+    assert_eq!(top.pop(), Some(Reverse(7)));
+
+    let mut smallest = ArrayHeap::<i32, 2>::new();
+    for x in [4, 9, 1, 7] {
+        smallest.push_top_k(x);
+    }
+    assert_eq!(smallest.into_sorted_array(), array!(1, 4));
+}
+
+#[test]
+fn test_array_heap_from_array() {
+    let heap = ArrayHeap::from(array!(2, 8, 5, 1, 9, 3; 8));
+    assert_eq!(heap.peek(), Some(&9));
+    assert_eq!(heap.len(), 6);
+    assert_eq!(heap.clone().into_sorted_array(), array!(1, 2, 3, 5, 8, 9));
+    let mut sorted: Vec<i32> = heap.into_iter().collect();
+    sorted.sort();
+    assert_eq!(sorted, vec![1, 2, 3, 5, 8, 9]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_small_array() {
+    let mut arr = SmallArray::<i32, 3>::new();
+    arr.extend(1..=3);
+    assert!(!arr.spilled());
+    assert_eq!(arr.capacity(), 3);
+    arr.insert(0, 0);
+    assert!(arr.spilled());
+    assert!(arr.capacity() >= 4);
+    assert_eq!(arr, [0, 1, 2, 3]);
+    assert_eq!(arr.remove(1), 1);
+    arr.shrink_to_inline();
+    assert!(!arr.spilled());
+    assert_eq!(arr, [0, 2, 3]);
+
+    arr.append(array!(4, 5));
+    assert_eq!(arr.len(), 5);
+    let arr = arr.filter(|&x| x != 3).map(|&x| x * 10);
+    assert!(arr.spilled());
+    assert_eq!(arr.into_vec(), vec![0, 20, 40, 50]);
+
+    let arr = SmallArray::<i32, 3>::from(vec![1, 2]);
+    assert!(!arr.spilled());
+    assert_eq!(arr.into_array().unwrap(), array!(1, 2; 3));
+    let arr: SmallArray<i32, 1> = (0..4).collect();
+    assert_eq!(arr.clone().into_array().unwrap_err(), [0, 1, 2, 3]);
+    assert_eq!(arr.into_iter().rev().collect::<Vec<_>>(), vec![3, 2, 1, 0]);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+#[should_panic(expected = "insert index (3) > len (2)")]
+fn test_small_array_insert_out_of_bounds() {
+    let mut arr: SmallArray<i32, 1> = (0..2).collect();
+    arr.insert(3, 0);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+#[should_panic(expected = "remove index (2) >= len (2)")]
+fn test_small_array_remove_out_of_bounds() {
+    let mut arr: SmallArray<i32, 1> = (0..2).collect();
+    arr.remove(2);
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn test_small_array_drop() {
+    let rc = Rc::new(());
+    let mut arr = SmallArray::<Rc<()>, 2>::new();
+    for _ in 0..5 {
+        arr.push(rc.clone());
+    }
+    assert_eq!(Rc::strong_count(&rc), 6);
+    arr.truncate(2);
+    arr.shrink_to_inline();
+    assert_eq!(Rc::strong_count(&rc), 3);
+    let mut iter = arr.into_iter();
+    iter.next();
+    drop(iter);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_grid() {
+    let mut grid = Grid::<usize, 3, 2>::from_fn(|x, y| y * 3 + x);
+    assert_eq!((grid.width(), grid.height(), grid.len()), (3, 2, 6));
+    assert_eq!(grid[(2, 1)], 5);
+    assert_eq!(grid.get(3, 0), None);
+    assert_eq!(grid.get(0, 2), None);
+    assert_eq!(grid.row(1), &[3, 4, 5]);
+    assert_eq!(grid.column(1).copied().collect::<Vec<_>>(), vec![1, 4]);
+    let columns: Vec<Vec<usize>> = grid.columns().map(|c| c.copied().collect()).collect();
+    assert_eq!(columns, vec![vec![0, 3], vec![1, 4], vec![2, 5]]);
+    for cell in grid.column_mut(0) {
+        *cell += 10;
+    }
+    assert_eq!(grid.as_slice(), &[10, 1, 2, 13, 4, 5]);
+    assert_eq!(
+        grid.positions().collect::<Vec<_>>(),
+        vec![(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)]
+    );
+    assert_eq!(grid.enumerate().nth(4), Some(((1, 1), &4)));
+    let grid = grid.map(|x| x * 2);
+    assert_eq!(grid.row(0), &[20, 2, 4]);
+}
+
+#[test]
+fn test_grid_neighbors() {
+    let grid = Grid::<u8, 3, 3>::default();
+    assert_eq!(
+        grid.neighbors4(0, 0).collect::<Vec<_>>(),
+        vec![(1, 0), (0, 1)]
+    );
+    assert_eq!(grid.neighbors4(1, 1).count(), 4);
+    assert_eq!(
+        grid.neighbors8(2, 2).collect::<Vec<_>>(),
+        vec![(1, 1), (2, 1), (1, 2)]
+    );
+    assert_eq!(grid.neighbors8(1, 1).count(), 8);
+}
+
+#[test]
+fn test_grid_transpose_and_views() {
+    let mut grid = Grid::<usize, 3, 3>::from_fn(|x, y| y * 3 + x);
+    grid.transpose();
+    assert_eq!(grid.into_rows(), [[0, 3, 6], [1, 4, 7], [2, 5, 8]]);
+
+    let view = grid.view(1, 1, 2, 2);
+    assert_eq!((view.width(), view.height()), (2, 2));
+    assert_eq!(
+        view.rows().collect::<Vec<_>>(),
+        vec![&[4, 7][..], &[5, 8][..]]
+    );
+    assert_eq!(view.get(2, 0), None);
+    assert_eq!(view.positions().count(), 4);
+
+    let mut view = grid.view_mut(0, 2, 3, 1);
+    view.fill(0);
+    view[(1, 0)] = 9;
+    assert_eq!(grid.row(2), &[0, 9, 0]);
+}
+
+#[test]
+#[should_panic(expected = "exceeds grid size")]
+fn test_grid_view_out_of_bounds() {
+    let grid = Grid::<u8, 2, 2>::default();
+    grid.view(1, 0, 2, 1);
+}
+
+#[test]
+#[should_panic(expected = "x (3) >= width (3)")]
+fn test_grid_column_out_of_bounds() {
+    let grid = Grid::<u8, 3, 2>::default();
+    let _ = grid.column(3);
+}
+
+#[test]
+fn test_bit_array() {
+    let mut bits = BitArray::<70, 2>::new();
+    for i in 0..70 {
+        bits.push(i % 3 == 0);
+    }
+    assert!(bits.is_full());
+    assert!(bits.try_push(true).unwrap_err().element());
+    assert_eq!(bits.count_ones(), 24);
+    assert_eq!(bits.count_zeros(), 46);
+    assert_eq!(bits.get(69), Some(true));
+    assert_eq!(bits.get(70), None);
+    bits.set(69, false);
+    bits.toggle(68);
+    assert_eq!(bits.next_set(67), Some(68));
+    assert_eq!(bits.pop(), Some(false));
+    assert_eq!(bits.pop(), Some(true));
+    assert_eq!(bits.len(), 68);
+    assert_eq!(bits.iter_ones().last(), Some(66));
+    bits.truncate(4);
+    assert_eq!(bits.to_string(), "1001");
+    assert_eq!(format!("{:?}", bits), "BitArray(1001)");
+    bits.clear();
+    assert_eq!(bits.first_set(), None);
+    assert!(bits.all());
+    assert!(!bits.any());
+}
+
+#[test]
+fn test_bit_array_set() {
+    let mut a = BitArray::<130, 3>::new();
+    assert!(a.insert(5));
+    assert!(!a.insert(5));
+    assert!(a.insert(129));
+    assert_eq!(a.len(), 130);
+    assert!(a.contains(129));
+    assert!(!a.contains(200));
+    assert!(a.remove(129));
+    assert!(!a.remove(129));
+
+    let b: BitArray<130, 3> = [1, 5, 64].into_iter().fold(BitArray::new(), |mut b, i| {
+        b.insert(i);
+        b
+    });
+    assert_eq!((a & b).iter_ones().collect::<Vec<_>>(), vec![5]);
+    assert_eq!((a | b).iter_ones().collect::<Vec<_>>(), vec![1, 5, 64]);
+    assert_eq!((a ^ b).iter_ones().collect::<Vec<_>>(), vec![1, 64]);
+    assert!((a & b).is_subset(&b));
+    assert!((a ^ b).is_disjoint(&(a & b)));
+    assert_eq!(b.len(), 65);
+    assert_eq!((!b).count_ones(), 65 - 3);
+    assert_eq!(BitArray::<130, 3>::ones().count_ones(), 130);
+}
+
+#[test]
+fn test_bit_array_shift() {
+    let bits: BitArray<130, 3> = (0..130).map(|i| i == 0 || i == 63 || i == 100).collect();
+    let shl = bits << 1;
+    assert_eq!(shl.iter_ones().collect::<Vec<_>>(), vec![1, 64, 101]);
+    let shl = bits << 65;
+    assert_eq!(shl.iter_ones().collect::<Vec<_>>(), vec![65, 128]);
+    assert_eq!((bits << 64).iter_ones().collect::<Vec<_>>(), vec![64, 127]);
+    let shr = bits >> 63;
+    assert_eq!(shr.iter_ones().collect::<Vec<_>>(), vec![0, 37]);
+    assert_eq!((bits >> 200).count_ones(), 0);
+    assert_eq!(shr.len(), 130);
+
+    let short: BitArray<8> = [true, true, true].into_iter().collect();
+    assert_eq!((short << 2).to_string(), "001");
+    assert_eq!((short >> 1).to_string(), "110");
+}
+
+#[test]
+fn test_words_for() {
+    assert_eq!([0, 1, 64, 65, 128, 200].map(words_for), [0, 1, 1, 2, 2, 4]);
+    let bits = BitArray::<65, { words_for(65) }>::ones();
+    assert_eq!(bits.count_ones(), 65);
+}
+
+#[test]
+#[should_panic(expected = "index (3) >= len (3)")]
+fn test_bit_array_set_out_of_bounds() {
+    let mut bits: BitArray<8> = [true, false, true].into_iter().collect();
+    bits.set(3, true);
+}
+
+#[test]
+#[should_panic(expected = "value (8) >= capacity (8)")]
+fn test_bit_array_insert_out_of_bounds() {
+    let mut bits = BitArray::<8>::new();
+    bits.insert(8);
+}
+
+#[test]
+fn test_insert_sorted() {
+    let mut arr = array!(1, 3, 3, 5; 6);
+    assert_eq!(arr.insert_sorted(3), Ok(3));
+    assert_eq!(arr.insert_sorted(6), Ok(5));
+    assert_eq!(arr, array!(1, 3, 3, 3, 5, 6));
+    assert_eq!(arr.insert_sorted(2).unwrap_err().element(), 2);
+
+    let mut words = Array::<&str, 4>::from(&["a", "ccc"]);
+    assert_eq!(words.insert_sorted_by_key("bb", |s| s.len()), Ok(1));
+    assert_eq!(words.insert_sorted_by_key("dd", |s| s.len()), Ok(2));
+    assert_eq!(words, ["a", "bb", "dd", "ccc"]);
+}
+
+#[test]
+fn test_sorted_array() {
+    let mut arr: SortedArray<i32, 8> = [7, 3, 9, 3, 1].into_iter().collect();
+    assert_eq!(arr.as_slice(), &[1, 3, 3, 7, 9]);
+    assert_eq!(arr.insert_sorted(5), Ok(3));
+    assert_eq!(arr.insert_dedup(5), Ok(false));
+    assert_eq!(arr.insert_dedup(4), Ok(true));
+    assert_eq!(arr.as_slice(), &[1, 3, 3, 4, 5, 7, 9]);
+    assert_eq!((arr.lower_bound(&3), arr.upper_bound(&3)), (1, 3));
+    assert_eq!((arr.lower_bound(&10), arr.upper_bound(&0)), (7, 0));
+    assert_eq!(arr.range(3..=5), &[3, 3, 4, 5]);
+    assert_eq!(arr.range(..4), &[1, 3, 3]);
+    assert_eq!(arr.range(8..), &[9]);
+    assert!(arr.range(5..5).is_empty());
+    assert!(arr.contains(&7));
+    assert_eq!(arr.remove_value(&3), Some(3));
+    assert_eq!(arr.remove_value(&6), None);
+    arr.insert_sorted(9).unwrap();
+    arr.dedup();
+    assert_eq!(arr.as_slice(), &[1, 3, 4, 5, 7, 9]);
+    assert_eq!(arr.pop_first(), Some(1));
+    assert_eq!(arr.pop_last(), Some(9));
+    arr.extend([8, 2]);
+    assert_eq!(arr.len(), 6);
+    assert_eq!(format!("{:?}", arr), "[2, 3, 4, 5, 7, 8]");
+    assert_eq!(arr.insert_dedup(0), Ok(true));
+    assert_eq!(arr.insert_dedup(0), Ok(false));
+    assert_eq!(arr.insert_dedup(6), Ok(true));
+    assert_eq!(arr.insert_dedup(1).unwrap_err().element(), 1);
+}
+
+#[test]
+fn test_sorted_array_merge() {
+    let a = SortedArray::from(array!(5, 1, 3, 3));
+    let b = SortedArray::from(array!(4, 3, 6; 4));
+    let merged: SortedArray<i32, 8> = a.merge(b);
+    assert_eq!(merged.as_slice(), &[1, 3, 3, 3, 4, 5, 6]);
+    let empty: SortedArray<i32, 8> =
+        SortedArray::<i32, 4>::new().merge(SortedArray::<i32, 4>::new());
+    assert!(empty.is_empty());
+
+    let (x, y, z) = (String::from("b"), String::from("a"), String::from("c"));
+    let strings: SortedArray<String, 4> = [x, y, z].into_iter().collect();
+    assert!(strings.contains("b"));
+    let from_b = (Bound::Included("b"), Bound::Unbounded);
+    assert_eq!(strings.range::<str, _>(from_b), &["b", "c"]);
+}
+
+#[test]
+fn test_spsc() {
+    let mut queue = spsc::Queue::<i32, 3>::new();
+    {
+        let (mut producer, mut consumer) = queue.split();
+        assert_eq!(consumer.try_pop(), None);
+        for round in 0..5 {
+            producer.try_push(round).unwrap();
+            producer.try_push(round + 10).unwrap();
+            assert_eq!(producer.free(), 1);
+            assert_eq!(consumer.peek(), Some(&round));
+            assert_eq!(consumer.try_pop(), Some(round));
+            assert_eq!(consumer.try_pop(), Some(round + 10));
+        }
+        producer.push_array(array!(1, 2, 3)).unwrap();
+        assert!(producer.is_full());
+        assert_eq!(producer.try_push(4).unwrap_err().element(), 4);
+        let mut out = array!(0,; 3);
+        assert_eq!(consumer.pop_into(&mut out), 2);
+        assert_eq!(out, array!(0, 1, 2));
+        assert_eq!(consumer.len(), 1);
+    }
+    assert_eq!(queue.len(), 1);
+
+    let mut empty = spsc::Queue::<i32, 0>::new();
+    let (mut producer, mut consumer) = empty.split();
+    assert!(producer.try_push(1).is_err());
+    assert_eq!(consumer.try_pop(), None);
+}
+
+#[test]
+fn test_spsc_drop() {
+    let rc = Rc::new(());
+    let mut queue = spsc::Queue::<Rc<()>, 4>::new();
+    let (mut producer, mut consumer) = queue.split();
+    for _ in 0..3 {
+        producer.try_push(rc.clone()).unwrap();
+    }
+    drop(consumer.try_pop());
+    assert_eq!(Rc::strong_count(&rc), 3);
+    drop(queue);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_spsc_threads() {
+    const N: usize = 20_000;
+    let mut queue = spsc::Queue::<Box<usize>, 16>::new();
+    let (mut producer, mut consumer) = queue.split();
+    std::thread::scope(|s| {
+        s.spawn(move || {
+            let mut i = 0;
+            while i < N {
+                let pushed = if i % 3 == 0 {
+                    let batch = array!(Box::new(i),; 1);
+                    producer.push_array(batch).is_ok()
+                } else {
+                    producer.try_push(Box::new(i)).is_ok()
+                };
+                if pushed {
+                    i += 1;
+                } else {
+                    std::thread::yield_now();
+                }
+            }
+        });
+        let mut expected = 0;
+        while expected < N {
+            let batch = consumer.pop_array::<5>();
+            for x in batch {
+                assert_eq!(*x, expected);
+                expected += 1;
+            }
+            match consumer.try_pop() {
+                Some(x) => {
+                    assert_eq!(*x, expected);
+                    expected += 1;
+                }
+                None => std::thread::yield_now(),
+            }
+        }
+        assert_eq!(consumer.try_pop(), None);
+    });
+}
+
+#[test]
+fn test_atomic_array() {
+    let mut arr = AtomicArray::<String, 3>::new();
+    arr.push("a".to_string());
+    arr.try_push("b".to_string()).unwrap();
+    assert_eq!(arr.len(), 2);
+    arr.push("c".to_string());
+    assert!(arr.is_full());
+    let err = arr.try_push("d".to_string()).unwrap_err();
+    assert_eq!(err.len(), 3);
+    assert_eq!(err.element(), "d");
+    arr.as_mut_slice()[0].push('!');
+    let taken = arr.take();
+    assert!(arr.is_empty());
+    assert_eq!(taken, ["a!", "b", "c"].map(String::from));
+
+    let arr = AtomicArray::from(taken);
+    assert_eq!(arr.len(), 3);
+    assert_eq!(arr.into_array().len(), 3);
+}
+
+#[test]
+fn test_atomic_array_drop() {
+    let rc = Rc::new(());
+    let mut arr = AtomicArray::<Rc<()>, 4>::new();
+    arr.push(rc.clone());
+    arr.push(rc.clone());
+    arr.clear();
+    assert_eq!(Rc::strong_count(&rc), 1);
+    arr.push(rc.clone());
+    drop(arr);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_atomic_array_threads() {
+    const THREADS: usize = 8;
+    const PER_THREAD: usize = 100;
+    let arr = AtomicArray::<Box<usize>, 600>::new();
+    let rejected = std::sync::atomic::AtomicUsize::new(0);
+    std::thread::scope(|s| {
+        for t in 0..THREADS {
+            let (arr, rejected) = (&arr, &rejected);
+            s.spawn(move || {
+                for i in 0..PER_THREAD {
+                    if arr.try_push(Box::new(t * PER_THREAD + i)).is_err() {
+                        rejected.fetch_add(1, core::sync::atomic::Ordering::Relaxed);
+                    }
+                }
+            });
+        }
+    });
+    assert_eq!(rejected.into_inner(), THREADS * PER_THREAD - 600);
+    let mut values: Vec<usize> = arr.into_array().into_iter().map(|b| *b).collect();
+    values.sort();
+    values.dedup();
+    assert_eq!(values.len(), 600);
+}
+
+#[test]
+fn test_array_slab() {
+    let mut slab = ArraySlab::<String, 3>::new();
+    let a = slab.insert("a".to_string());
+    let b = slab.try_insert("b".to_string()).unwrap();
+    let c = slab.insert("c".to_string());
+    assert_eq!((a, b, c), (0, 1, 2));
+    assert!(slab.is_full());
+    assert_eq!(slab.vacant_key(), None);
+    assert!(slab.vacant_entry().is_none());
+    assert_eq!(slab.try_insert("d".to_string()).unwrap_err().element(), "d");
+
+    assert_eq!(slab.remove(b), "b");
+    assert_eq!(slab.try_remove(b), None);
+    assert!(!slab.contains(b));
+    assert_eq!(slab.remove(a), "a");
+    assert_eq!(slab.len(), 1);
+    // freed slots are reused last-in first-out
+    assert_eq!(slab.vacant_key(), Some(a));
+    assert_eq!(slab.insert("e".to_string()), a);
+    assert_eq!(slab.insert("f".to_string()), b);
+    slab[c].push('!');
+    assert_eq!(format!("{:?}", slab), r#"{0: "e", 1: "f", 2: "c!"}"#);
+
+    slab.retain(|key, _| key != 1);
+    assert_eq!(slab.iter().map(|(k, _)| k).collect::<Vec<_>>(), vec![0, 2]);
+    for (_, val) in slab.iter_mut() {
+        val.make_ascii_uppercase();
+    }
+    assert_eq!(slab.get(2).map(String::as_str), Some("C!"));
+    assert_eq!(slab.get(5), None);
+    slab.clear();
+    assert!(slab.is_empty());
+    assert_eq!(slab.insert("g".to_string()), 0);
+}
+
+#[test]
+#[should_panic(expected = "invalid slab key")]
+fn test_array_slab_invalid_key() {
+    let mut slab = ArraySlab::<i32, 2>::new();
+    let key = slab.insert(1);
+    slab.remove(key);
+    slab.remove(key);
+}
+
+#[test]
+fn test_array_arena() {
+    let mut arena = ArrayArena::<String, 2>::new();
+    let a = arena.insert("a".to_string());
+    let b = arena.try_insert("b".to_string()).unwrap();
+    assert_eq!(
+        arena.try_insert("c".to_string()).unwrap_err().element(),
+        "c"
+    );
+    assert_eq!(arena[a], "a");
+    arena[b].push('!');
+
+    assert_eq!(arena.remove(a).as_deref(), Some("a"));
+    assert_eq!(arena.remove(a), None);
+    let c = arena.insert("c".to_string());
+    assert_eq!((c.index(), c.generation()), (a.index(), a.generation() + 1));
+    assert_ne!(a, c);
+    assert!(!arena.contains(a));
+    assert_eq!(arena.get_mut(a), None);
+    assert_eq!(
+        format!("{:?}", arena),
+        r#"{Handle(0v1): "c", Handle(1v0): "b!"}"#
+    );
+
+    arena.retain(|handle, _| handle != b);
+    assert_eq!(arena.len(), 1);
+    assert_eq!(arena.iter().map(|(h, _)| h).collect::<Vec<_>>(), vec![c]);
+    for (_, value) in arena.iter_mut() {
+        value.push('?');
+    }
+    assert_eq!(arena.get(c).map(String::as_str), Some("c?"));
+
+    arena.clear();
+    assert!(arena.is_empty());
+    assert_eq!(arena.get(c), None);
+    let d = arena.insert("d".to_string());
+    let e = arena.insert("e".to_string());
+    assert_eq!((d.index(), d.generation()), (0, 2));
+    assert_eq!((e.index(), e.generation()), (1, 1));
+}
+
+#[test]
+fn test_array_arena_drop() {
+    let rc = Rc::new(());
+    let mut arena = ArrayArena::<Rc<()>, 4>::new();
+    let handles: Vec<_> = (0..4).map(|_| arena.insert(rc.clone())).collect();
+    arena.remove(handles[1]);
+    assert_eq!(Rc::strong_count(&rc), 4);
+    arena.clear();
+    assert_eq!(Rc::strong_count(&rc), 1);
+    arena.insert(rc.clone());
+    drop(arena);
+    assert_eq!(Rc::strong_count(&rc), 1);
+}
+
+#[test]
+fn test_array_lru() {
+    fn keys<const L: usize>(lru: &ArrayLru<i32, String, L>) -> Vec<i32> {
+        lru.iter().map(|(k, _)| *k).collect()
+    }
+
+    let mut lru = ArrayLru::<i32, String, 3>::new();
+    for k in 1..=3 {
+        assert_eq!(lru.put(k, k.to_string()), lru::Put::Inserted);
+    }
+    assert_eq!(keys(&lru), vec![3, 2, 1]);
+    assert_eq!(lru.get(&1).map(String::as_str), Some("1"));
+    assert_eq!(keys(&lru), vec![1, 3, 2]);
+    assert_eq!(lru.peek(&2).map(String::as_str), Some("2"));
+    assert_eq!(keys(&lru), vec![1, 3, 2]);
+    assert_eq!(lru.peek_lru(), Some((&2, &"2".to_string())));
+
+    assert_eq!(
+        lru.put(4, "4".to_string()),
+        lru::Put::Evicted(2, "2".to_string())
+    );
+    assert_eq!(
+        lru.put(3, "three".to_string()),
+        lru::Put::Replaced("3".to_string())
+    );
+    assert_eq!(keys(&lru), vec![3, 4, 1]);
+    assert_eq!(
+        lru.iter().rev().map(|(k, _)| *k).collect::<Vec<_>>(),
+        vec![1, 4, 3]
+    );
+    assert_eq!(lru.iter().len(), 3);
+
+    lru.get_mut(&4).unwrap().push('!');
+    assert_eq!(lru.get(&2), None);
+    assert_eq!((lru.hits(), lru.misses()), (2, 1));
+    lru.reset_stats();
+    assert_eq!((lru.hits(), lru.misses()), (0, 0));
+
+    // removing from the middle relinks the entry moved into the hole
+    assert_eq!(lru.remove(&3).as_deref(), Some("three"));
+    assert_eq!(keys(&lru), vec![4, 1]);
+    assert_eq!(format!("{:?}", lru), r#"{4: "4!", 1: "1"}"#);
+    lru.put(5, "5".to_string());
+    assert_eq!(lru.pop_lru(), Some((1, "1".to_string())));
+    assert_eq!(keys(&lru), vec![5, 4]);
+    assert!(lru.contains(&4));
+    lru.clear();
+    assert!(lru.is_empty());
+    assert_eq!(lru.pop_lru(), None);
+
+    let mut none = ArrayLru::<i32, i32, 0>::new();
+    assert_eq!(none.put(1, 1), lru::Put::Rejected(1, 1));
+    assert!(none.is_empty());
+}
+
+#[test]
+fn test_array_lru_put_replace_vs_evict() {
+    let mut lru = ArrayLru::<&str, u32, 2>::new();
+    assert_eq!(lru.put("a", 1), lru::Put::Inserted);
+    assert_eq!(lru.put("b", 2), lru::Put::Inserted);
+    // updating a key of a full cache replaces in place and evicts nothing
+    assert_eq!(lru.put("a", 10), lru::Put::Replaced(1));
+    assert_eq!(lru.len(), 2);
+    assert_eq!(lru.peek("b"), Some(&2));
+    // the update promoted "a", so "b" is the one evicted
+    assert_eq!(lru.put("c", 3), lru::Put::Evicted("b", 2));
+    assert_eq!(lru.peek("a"), Some(&10));
+}
+
+#[test]
+fn test_array_lru_churn() {
+    let mut lru = ArrayLru::<u32, u32, 4>::new();
+    let mut model: Vec<u32> = Vec::new();
+    for i in 0..200u32 {
+        let key = (i * 7 + i / 3) % 9;
+        if i % 5 == 0 {
+            lru.remove(&key);
+            model.retain(|&k| k != key);
+        } else if lru.get(&key).is_none() {
+            let evicted = lru.put(key, key);
+            if model.len() == 4 {
+                assert_eq!(evicted, lru::Put::Evicted(model[3], model[3]));
+                model.pop();
+            } else {
+                assert_eq!(evicted, lru::Put::Inserted);
+            }
+            model.insert(0, key);
+        } else {
+            model.retain(|&k| k != key);
+            model.insert(0, key);
+        }
+        assert_eq!(lru.iter().map(|(k, _)| *k).collect::<Vec<_>>(), model);
+    }
+}
+
+#[test]
+fn test_sparse_set() {
+    let mut set: SparseSet<16> = [3, 9, 1].into_iter().collect();
+    assert!(!set.insert(9));
+    assert!(set.insert(15));
+    assert_eq!(set.as_slice(), &[3, 9, 1, 15]);
+    assert!(set.contains(1));
+    assert!(!set.contains(2));
+    assert!(!set.contains(100));
+    assert!(set.remove(3));
+    assert!(!set.remove(3));
+    assert_eq!(set.as_slice(), &[15, 9, 1]);
+    assert!(set.contains(15));
+    assert!(set.remove(1));
+    assert_eq!(set.iter().copied().collect::<Vec<_>>(), vec![15, 9]);
+
+    set.clear();
+    assert!(set.is_empty());
+    // stale sparse entries are not mistaken for members
+    assert!(!set.contains(15));
+    assert!(set.insert(9));
+    assert!(!set.contains(15));
+    assert_eq!(format!("{:?}", set), "{9}");
+
+    let mut full = SparseSet::<4>::new();
+    full.extend(0..4);
+    assert_eq!(full.len(), full.capacity());
+}
+
+#[test]
+#[should_panic(expected = "id (4) >= capacity (4)")]
+fn test_sparse_set_out_of_range() {
+    SparseSet::<4>::new().insert(4);
+}
+
+#[test]
+fn test_sparse_map() {
+    let mut map = SparseMap::<String, 8>::new();
+    assert_eq!(map.insert(5, "five".to_string()), None);
+    assert_eq!(map.insert(2, "two".to_string()), None);
+    assert_eq!(map.insert(7, "seven".to_string()), None);
+    assert_eq!(map.insert(2, "TWO".to_string()).as_deref(), Some("two"));
+    assert_eq!(map.ids(), &[5, 2, 7]);
+    assert_eq!(map[2], "TWO");
+    map[7].push('!');
+    assert_eq!(map.remove(5).as_deref(), Some("five"));
+    assert_eq!(map.remove(5), None);
+    assert_eq!(map.ids(), &[7, 2]);
+    assert_eq!(map.values(), &["seven!", "TWO"]);
+    assert_eq!(map.get(7).map(String::as_str), Some("seven!"));
+    for (id, value) in map.iter_mut() {
+        value.push_str(&id.to_string());
+    }
+    assert_eq!(format!("{:?}", map), r#"{7: "seven!7", 2: "TWO2"}"#);
+    assert!(map.contains_key(2));
+    assert!(!map.contains_key(9));
+
+    let cloned = map.clone();
+    map.clear();
+    assert!(map.is_empty());
+    assert_eq!(map.get(7), None);
+    assert_eq!(cloned.len(), 2);
+
+    let map: SparseMap<i32, 4> = [(0, 1), (3, 4)].into_iter().collect();
+    assert_eq!(map.iter().rev().collect::<Vec<_>>(), vec![(3, &4), (0, &1)]);
+}
+
+#[test]
+fn test_ring_history() {
+    let mut history = RingHistory::<i32, 4>::new();
+    assert_eq!(
+        (history.min(), history.max(), history.mean()),
+        (None, None, None)
+    );
+    assert_eq!(history.sum(), 0);
+    for x in 1..=4 {
+        assert_eq!(history.push(x), None);
+    }
+    assert!(history.is_full());
+    assert_eq!(history.push(5), Some(1));
+    assert_eq!(history.push(-2), Some(2));
+    assert_eq!(
+        history.iter().copied().collect::<Vec<_>>(),
+        vec![3, 4, 5, -2]
+    );
+    let (a, b) = history.as_slices();
+    assert_eq!([a, b].concat(), vec![3, 4, 5, -2]);
+    assert_eq!((history.oldest(), history.newest()), (Some(&3), Some(&-2)));
+    assert_eq!(history.get(1), Some(&4));
+    for n in 0..=5 {
+        let expected: Vec<i32> = [3, 4, 5, -2][4 - n.min(4)..].to_vec();
+        assert_eq!(history.latest(n).copied().collect::<Vec<_>>(), expected);
+    }
+    assert_eq!(history.latest(3).next_back(), Some(&-2));
+    assert_eq!(history.sum(), 10);
+    assert_eq!(history.mean(), Some(2.5));
+    assert_eq!((history.min(), history.max()), (Some(&-2), Some(&5)));
+    assert_eq!(format!("{:?}", history), "[3, 4, 5, -2]");
+    history.clear();
+    assert!(history.is_empty());
+
+    let floats: RingHistory<f64, 4> = [9.0, f64::NAN, 2.0, 0.5, 1.5].into_iter().collect();
+    assert_eq!(floats.min(), Some(&0.5));
+    assert_eq!(floats.max(), Some(&2.0));
+    let floats: RingHistory<f64, 3> = [1.0, 2.0, f64::NAN].into_iter().collect();
+    assert_eq!((floats.min(), floats.max()), (Some(&1.0), Some(&2.0)));
+    let floats: RingHistory<f64, 3> = [f64::NAN, 0.5, 1.5].into_iter().collect();
+    assert_eq!((floats.min(), floats.max()), (Some(&0.5), Some(&1.5)));
+    assert!(floats.sum().is_nan());
+
+    let mut empty = RingHistory::<i32, 0>::new();
+    assert_eq!(empty.push(1), Some(1));
+    assert_eq!(empty.latest(1).count(), 0);
+}
+
+#[test]
+fn test_ring_history_mean_wide_ints() {
+    let latencies: RingHistory<u64, 3> = [7, 1 << 40, 1 << 41, 3 << 40].into_iter().collect();
+    assert_eq!(latencies.mean(), Some((1u64 << 41) as f64));
+    let deltas: RingHistory<isize, 2> = [-3, 4].into_iter().collect();
+    assert_eq!(deltas.mean(), Some(0.5));
+}